@@ -0,0 +1,274 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    error::Error,
+    fmt,
+    ops::ControlFlow::{self, Break, Continue},
+};
+
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// Fixes all but two numbers and looks the last one up in a hash map.
+    HashSet,
+    /// Fixes all but two numbers and walks the sorted rest from both ends.
+    TwoPointers,
+    /// Joins the sums of the lower half of each combination with the sums of the upper half.
+    MeetInTheMiddle,
+}
+
+impl Strategy {
+    #[must_use]
+    pub const fn for_arity(k: usize) -> Self {
+        match k {
+            0..=2 => Self::HashSet,
+            3 => Self::TwoPointers,
+            _ => Self::MeetInTheMiddle,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KSumError {
+    NoSolution,
+    Ambiguous(Vec<Vec<i64>>),
+}
+
+impl fmt::Display for KSumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSolution => write!(f, "No combination has the target sum"),
+            Self::Ambiguous(solutions) => write!(
+                f,
+                "{} distinct combinations have the target sum: {solutions:?}",
+                solutions.len()
+            ),
+        }
+    }
+}
+
+impl Error for KSumError {}
+
+/// Finds `k` numbers at distinct positions which sum up to `target`.
+///
+/// Combinations are reported as ascending indices into the searched numbers.
+#[derive(Clone, Copy, Debug)]
+pub struct KSum {
+    target: i64,
+    k: usize,
+    strategy: Strategy,
+}
+
+impl KSum {
+    #[must_use]
+    pub const fn new(target: i64, k: usize) -> Self {
+        Self {
+            target,
+            k,
+            strategy: Strategy::for_arity(k),
+        }
+    }
+
+    #[must_use]
+    pub const fn with_strategy(self, strategy: Strategy) -> Self {
+        Self { strategy, ..self }
+    }
+
+    #[must_use]
+    pub fn first(&self, nums: &[i64]) -> Option<Vec<usize>> {
+        let mut found = None;
+        self.visit(nums, &mut |indices| {
+            found = Some(indices.to_vec());
+            Break(())
+        });
+
+        found
+    }
+
+    #[must_use]
+    pub fn all(&self, nums: &[i64]) -> Vec<Vec<usize>> {
+        let mut found = vec![];
+        self.visit(nums, &mut |indices| {
+            found.push(indices.to_vec());
+            Continue(())
+        });
+
+        found.sort_unstable();
+        found
+    }
+
+    /// Every distinct combination of values, each sorted ascending, no matter how many positions
+    /// hold the same value.
+    #[must_use]
+    pub fn distinct(&self, nums: &[i64]) -> Vec<Vec<i64>> {
+        let mut found = BTreeSet::new();
+        self.visit(nums, &mut |indices| {
+            let mut values: Vec<i64> = indices.iter().map(|&i| nums[i]).collect();
+            values.sort_unstable();
+            found.insert(values);
+            Continue(())
+        });
+
+        found.into_iter().collect()
+    }
+
+    /// The only distinct combination of values, failing when there is none or more than one.
+    pub fn unique(&self, nums: &[i64]) -> Result<Vec<i64>, KSumError> {
+        let mut solutions = self.distinct(nums);
+        match solutions.len() {
+            0 => Err(KSumError::NoSolution),
+            1 => Ok(solutions.remove(0)),
+            _ => Err(KSumError::Ambiguous(solutions)),
+        }
+    }
+
+    /// Sums are taken in `i128`, so that no combination of `i64` values can overflow.
+    fn visit(&self, nums: &[i64], visit: &mut dyn FnMut(&[usize]) -> ControlFlow<()>) {
+        let nums: Vec<i128> = nums.iter().map(|&num| i128::from(num)).collect();
+        let target = i128::from(self.target);
+
+        if self.k < 2 || self.strategy == Strategy::MeetInTheMiddle {
+            let _ = meet_in_the_middle(&nums, target, self.k, visit);
+            return;
+        }
+
+        let order: Vec<usize> = (0..nums.len()).sorted_by_key(|&i| nums[i]).collect();
+        let sorted: Vec<i128> = order.iter().map(|&i| nums[i]).collect();
+
+        let mut fixed = Vec::with_capacity(self.k);
+        let _ = fix_prefix(
+            &sorted,
+            target,
+            self.k,
+            self.strategy,
+            &mut fixed,
+            &mut |positions| {
+                let mut indices: Vec<usize> = positions.iter().map(|&p| order[p]).collect();
+                indices.sort_unstable();
+                visit(&indices)
+            },
+        );
+    }
+}
+
+/// Fixes the numbers of a combination one by one until only a pair is left to find.
+fn fix_prefix(
+    sorted: &[i128],
+    target: i128,
+    k: usize,
+    strategy: Strategy,
+    fixed: &mut Vec<usize>,
+    visit: &mut dyn FnMut(&[usize]) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    let start = fixed.last().map_or(0, |&p| p + 1);
+    if k == 2 {
+        let mut visit_pair = |a, b| {
+            fixed.extend([a, b]);
+            let flow = visit(fixed);
+            fixed.truncate(fixed.len() - 2);
+            flow
+        };
+
+        return match strategy {
+            Strategy::TwoPointers => two_pointers(sorted, start, target, &mut visit_pair),
+            _ => hash_set(sorted, start, target, &mut visit_pair),
+        };
+    }
+
+    for p in start..sorted.len() {
+        fixed.push(p);
+        let flow = fix_prefix(sorted, target - sorted[p], k - 1, strategy, fixed, visit);
+        fixed.pop();
+        flow?;
+    }
+
+    Continue(())
+}
+
+fn hash_set(
+    sorted: &[i128],
+    start: usize,
+    target: i128,
+    visit: &mut dyn FnMut(usize, usize) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    let mut seen: HashMap<i128, Vec<usize>> = HashMap::new();
+    for (b, &value) in sorted.iter().enumerate().skip(start) {
+        for &a in seen.get(&(target - value)).into_iter().flatten() {
+            visit(a, b)?;
+        }
+        seen.entry(value).or_default().push(b);
+    }
+
+    Continue(())
+}
+
+fn two_pointers(
+    sorted: &[i128],
+    start: usize,
+    target: i128,
+    visit: &mut dyn FnMut(usize, usize) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    if sorted.len() < start + 2 {
+        return Continue(());
+    }
+
+    let (mut l, mut r) = (start, sorted.len() - 1);
+    while l < r {
+        let sum = sorted[l] + sorted[r];
+        if sum < target {
+            l += 1;
+        } else if sum > target {
+            r -= 1;
+        } else if sorted[l] == sorted[r] {
+            for (a, b) in (l..=r).tuple_combinations() {
+                visit(a, b)?;
+            }
+            break;
+        } else {
+            let l_end = (l..r).find(|&p| sorted[p] != sorted[l]).unwrap_or(r);
+            let r_start = (l_end..=r).find(|&p| sorted[p] == sorted[r]).unwrap_or(r);
+            for a in l..l_end {
+                for b in r_start..=r {
+                    visit(a, b)?;
+                }
+            }
+            l = l_end;
+            r = r_start - 1;
+        }
+    }
+
+    Continue(())
+}
+
+fn meet_in_the_middle(
+    nums: &[i128],
+    target: i128,
+    k: usize,
+    visit: &mut dyn FnMut(&[usize]) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    let sum = |indices: &[usize]| indices.iter().map(|&i| nums[i]).sum::<i128>();
+
+    let mut lower_halves: HashMap<i128, Vec<Vec<usize>>> = HashMap::new();
+    for lower in (0..nums.len()).combinations(k / 2) {
+        lower_halves.entry(sum(&lower)).or_default().push(lower);
+    }
+
+    for upper in (0..nums.len()).combinations(k - k / 2) {
+        for lower in lower_halves
+            .get(&(target - sum(&upper)))
+            .into_iter()
+            .flatten()
+        {
+            if let (Some(&last), Some(&first)) = (lower.last(), upper.first()) {
+                if last >= first {
+                    continue;
+                }
+            }
+
+            let indices: Vec<usize> = lower.iter().chain(&upper).copied().collect();
+            visit(&indices)?;
+        }
+    }
+
+    Continue(())
+}
@@ -0,0 +1,155 @@
+mod k_sum;
+
+use anyhow::Context;
+use aoc_core::{parse, ParseError, Solution};
+
+pub use k_sum::{KSum, KSumError, Strategy};
+
+fn product_of_2020_sum(nums: &[i64], k: usize) -> anyhow::Result<i64> {
+    let indices = KSum::new(2020, k)
+        .first(nums)
+        .with_context(|| format!("No {k} numbers had a sum of 2020"))?;
+
+    indices
+        .iter()
+        .try_fold(1_i64, |product, &i| product.checked_mul(nums[i]))
+        .with_context(|| format!("The product of the {k} numbers overflows"))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(str::trim)
+            .filter(|&line| !line.is_empty())
+            .map(|line| parse::number(input, line))
+            .collect()
+    }
+
+    fn part1(nums: &Self::Input) -> anyhow::Result<Self::Part1> {
+        product_of_2020_sum(nums, 2)
+    }
+
+    fn part2(nums: &Self::Input) -> anyhow::Result<Self::Part2> {
+        product_of_2020_sum(nums, 3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    const EXAMPLE: &str = "1721
+979
+366
+299
+675
+1456
+";
+
+    #[test]
+    fn example_part1() {
+        let nums = Day1::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day1::part1(&nums).unwrap(), 514_579);
+    }
+
+    #[test]
+    fn example_part2() {
+        let nums = Day1::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day1::part2(&nums).unwrap(), 241_861_950);
+    }
+
+    #[test]
+    fn extreme_values_do_not_overflow() {
+        let nums = [i64::MIN, 5, i64::MAX, 2021, -1];
+
+        for strategy in [
+            Strategy::HashSet,
+            Strategy::TwoPointers,
+            Strategy::MeetInTheMiddle,
+        ] {
+            assert_eq!(
+                KSum::new(-1, 2).with_strategy(strategy).all(&nums),
+                vec![vec![0, 2]],
+                "{strategy:?}"
+            );
+            assert_eq!(
+                KSum::new(2020, 2).with_strategy(strategy).all(&nums),
+                vec![vec![3, 4]],
+                "{strategy:?}"
+            );
+            assert_eq!(
+                KSum::new(2020, 3).with_strategy(strategy).all(&nums),
+                vec![vec![0, 2, 3]],
+                "{strategy:?}"
+            );
+        }
+
+        assert!(Day1::part2(&nums.to_vec()).is_err());
+        assert!(Day1::part1(&vec![i64::MIN, 5]).is_err());
+    }
+
+    #[test]
+    fn strategies_find_every_combination() {
+        let nums = [3, -1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 0];
+
+        for k in 0..=5 {
+            for target in [0, 8, 15] {
+                let expected: Vec<Vec<usize>> = (0..nums.len())
+                    .combinations(k)
+                    .filter(|indices| indices.iter().map(|&i| nums[i]).sum::<i64>() == target)
+                    .collect();
+
+                for strategy in [
+                    Strategy::HashSet,
+                    Strategy::TwoPointers,
+                    Strategy::MeetInTheMiddle,
+                ] {
+                    let k_sum = KSum::new(target, k).with_strategy(strategy);
+                    assert_eq!(
+                        k_sum.all(&nums),
+                        expected,
+                        "{strategy:?} k={k} target={target}"
+                    );
+                    assert_eq!(
+                        k_sum.first(&nums).is_some(),
+                        !expected.is_empty(),
+                        "{strategy:?} k={k} target={target}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn distinct_solutions_ignore_repeated_values() {
+        let nums = [1010, 1010, 1010, 2019, 1, 1];
+
+        assert_eq!(KSum::new(2020, 2).all(&nums).len(), 5);
+        assert_eq!(
+            KSum::new(2020, 2).distinct(&nums),
+            vec![vec![1, 2019], vec![1010, 1010]]
+        );
+        assert_eq!(
+            KSum::new(2020, 2).unique(&nums),
+            Err(KSumError::Ambiguous(vec![vec![1, 2019], vec![1010, 1010]]))
+        );
+        assert_eq!(KSum::new(4039, 3).unique(&nums), Ok(vec![1010, 1010, 2019]));
+        assert_eq!(KSum::new(5000, 2).unique(&nums), Err(KSumError::NoSolution));
+    }
+
+    #[test]
+    fn real_input() {
+        aoc_core::testing::assert_real_answers::<Day1>(2020, 1);
+    }
+}
@@ -0,0 +1,271 @@
+use std::{collections::HashMap, error::Error, fmt};
+
+use aoc_core::{parse, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverflowError;
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The location list totals overflowed")
+    }
+}
+
+impl Error for OverflowError {}
+
+/// Left and right location ID lists which can grow while the totals are being queried.
+///
+/// Both lists are kept as counts per ID, so the similarity score is updated on every push and the
+/// distance only sorts the distinct IDs.
+#[derive(Clone, Debug, Default)]
+pub struct LocationLists {
+    left: HashMap<i32, u64>,
+    right: HashMap<i32, u64>,
+    similarity: i128,
+    /// A count overflowed, which spoils both totals.
+    counts_overflowed: bool,
+    similarity_overflowed: bool,
+}
+
+impl LocationLists {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push_left(&mut self, id: i32) {
+        self.push_left_repeated(id, 1);
+    }
+
+    pub fn push_right(&mut self, id: i32) {
+        self.push_right_repeated(id, 1);
+    }
+
+    pub fn push_left_repeated(&mut self, id: i32, count: u64) {
+        Self::add_count(&mut self.left, id, count, &mut self.counts_overflowed);
+        let matches = self.right.get(&id).copied().unwrap_or(0);
+        self.add_similarity(id, count, matches);
+    }
+
+    pub fn push_right_repeated(&mut self, id: i32, count: u64) {
+        Self::add_count(&mut self.right, id, count, &mut self.counts_overflowed);
+        let matches = self.left.get(&id).copied().unwrap_or(0);
+        self.add_similarity(id, count, matches);
+    }
+
+    pub fn push(&mut self, left: i32, right: i32) {
+        self.push_left(left);
+        self.push_right(right);
+    }
+
+    /// Sum of the differences between the smallest IDs of both lists, then the second smallest
+    /// ones and so on, ignoring the IDs which have no counterpart yet.
+    pub fn distance(&self) -> Result<u64, OverflowError> {
+        if self.counts_overflowed {
+            return Err(OverflowError);
+        }
+
+        let mut left = Self::sorted_counts(&self.left).into_iter();
+        let mut right = Self::sorted_counts(&self.right).into_iter();
+
+        let mut distance: u64 = 0;
+        let (mut left_entry, mut right_entry) = (left.next(), right.next());
+        while let (Some((left_id, left_count)), Some((right_id, right_count))) =
+            (&mut left_entry, &mut right_entry)
+        {
+            let paired = (*left_count).min(*right_count);
+            let difference = (i64::from(*left_id) - i64::from(*right_id)).unsigned_abs();
+            distance = difference
+                .checked_mul(paired)
+                .and_then(|pair_distance| distance.checked_add(pair_distance))
+                .ok_or(OverflowError)?;
+
+            *left_count -= paired;
+            *right_count -= paired;
+            if *left_count == 0 {
+                left_entry = left.next();
+            }
+            if *right_count == 0 {
+                right_entry = right.next();
+            }
+        }
+
+        Ok(distance)
+    }
+
+    /// Sum of each left ID multiplied by the number of its occurrences in the right list.
+    pub const fn similarity(&self) -> Result<i128, OverflowError> {
+        if self.counts_overflowed || self.similarity_overflowed {
+            return Err(OverflowError);
+        }
+
+        Ok(self.similarity)
+    }
+
+    fn add_count(counts: &mut HashMap<i32, u64>, id: i32, count: u64, overflowed: &mut bool) {
+        let total = counts.entry(id).or_default();
+        match total.checked_add(count) {
+            Some(sum) => *total = sum,
+            None => *overflowed = true,
+        }
+    }
+
+    fn add_similarity(&mut self, id: i32, count: u64, matches: u64) {
+        let added = i128::from(id)
+            .checked_mul(i128::from(count))
+            .and_then(|score| score.checked_mul(i128::from(matches)))
+            .and_then(|score| self.similarity.checked_add(score));
+
+        match added {
+            Some(similarity) => self.similarity = similarity,
+            None => self.similarity_overflowed = true,
+        }
+    }
+
+    fn sorted_counts(counts: &HashMap<i32, u64>) -> Vec<(i32, u64)> {
+        let mut counts: Vec<(i32, u64)> = counts.iter().map(|(&id, &count)| (id, count)).collect();
+        counts.sort_unstable();
+        counts
+    }
+}
+
+impl Extend<(i32, i32)> for LocationLists {
+    fn extend<I: IntoIterator<Item = (i32, i32)>>(&mut self, pairs: I) {
+        for (left, right) in pairs {
+            self.push(left, right);
+        }
+    }
+}
+
+impl FromIterator<(i32, i32)> for LocationLists {
+    fn from_iter<I: IntoIterator<Item = (i32, i32)>>(pairs: I) -> Self {
+        let mut lists = Self::new();
+        lists.extend(pairs);
+        lists
+    }
+}
+
+fn parse_input(input: &str) -> Result<LocationLists, ParseError> {
+    let lines = input.lines();
+
+    lines
+        .map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            match parts[..] {
+                [left, right] => Ok((parse::number(input, left)?, parse::number(input, right)?)),
+                [_, _, extra, ..] => Err(ParseError::at(input, extra, "the end of the line")),
+                _ => Err(ParseError::at(input, &line[line.len()..], "two numbers")),
+            }
+        })
+        .collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = LocationLists;
+    type Part1 = u64;
+    type Part2 = i128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(lists: &Self::Input) -> anyhow::Result<Self::Part1> {
+        Ok(lists.distance()?)
+    }
+
+    fn part2(lists: &Self::Input) -> anyhow::Result<Self::Part2> {
+        Ok(lists.similarity()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day1::part1(&input).unwrap(), 11);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day1::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day1::part2(&input).unwrap(), 31);
+    }
+
+    #[test]
+    fn similarity_counts_left_duplicates_after_right_list_is_exhausted() {
+        let mut lists = LocationLists::new();
+        lists.extend([3, 3, 3].into_iter().zip([3]));
+        lists.push_left(3);
+        lists.push_left(3);
+
+        assert_eq!(lists.similarity(), Ok(9));
+    }
+
+    #[test]
+    fn totals_are_up_to_date_after_every_chunk() {
+        let mut lists = LocationLists::new();
+        let pairs = [(3, 4), (4, 3), (2, 5), (1, 3), (3, 9), (3, 3)];
+
+        lists.extend(pairs[..2].iter().copied());
+        assert_eq!((lists.distance(), lists.similarity()), (Ok(0), Ok(7)));
+
+        lists.push_left(2);
+        assert_eq!((lists.distance(), lists.similarity()), (Ok(2), Ok(7)));
+
+        lists.push_right(5);
+        lists.extend(pairs[3..].iter().copied());
+        assert_eq!((lists.distance(), lists.similarity()), (Ok(11), Ok(31)));
+    }
+
+    #[test]
+    fn reports_overflowing_totals() {
+        let mut lists = LocationLists::new();
+        lists.push_left_repeated(i32::MIN, u64::MAX / 2);
+        lists.push_right_repeated(i32::MAX, u64::MAX / 2);
+
+        assert_eq!(lists.distance(), Err(OverflowError));
+        assert_eq!(lists.similarity(), Ok(0));
+
+        lists.push_left_repeated(i32::MIN, u64::MAX);
+        assert_eq!(lists.similarity(), Err(OverflowError));
+
+        let mut lists = LocationLists::new();
+        lists.push_left_repeated(i32::MAX, u64::MAX);
+        lists.push_right_repeated(i32::MAX, u64::MAX);
+
+        assert_eq!(lists.similarity(), Err(OverflowError));
+        assert_eq!(lists.distance(), Ok(0));
+    }
+
+    #[test]
+    fn rejects_a_missing_number() {
+        assert_eq!(
+            Day1::parse("3   4\n4 x\n5\n").err(),
+            Some(ParseError::new(2, 3, "x", "a number"))
+        );
+        assert_eq!(
+            Day1::parse("3   4\n5\n").err(),
+            Some(ParseError::new(2, 2, "", "two numbers"))
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        aoc_core::testing::assert_real_answers::<Day1>(2024, 1);
+    }
+}
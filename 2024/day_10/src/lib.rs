@@ -0,0 +1,151 @@
+use aoc_core::{ParseError, Solution};
+use grid::{Grid, Point};
+use rayon::prelude::*;
+use rustc_hash::FxHashSet as HashSet;
+
+pub struct TopographicMap {
+    terrain: Grid<u32>,
+}
+
+impl TopographicMap {
+    const fn new(terrain: Grid<u32>) -> Self {
+        Self { terrain }
+    }
+
+    fn find_trail_heads(&self) -> Vec<Point> {
+        self.terrain
+            .iter()
+            .filter(|(_, &terrain_height)| terrain_height == 0)
+            .map(|(point, _)| point)
+            .collect()
+    }
+
+    fn find_reachable_targets(&self, target_height: u32) -> u32 {
+        let trail_heads: Vec<Point> = self.find_trail_heads();
+        if trail_heads.is_empty() {
+            return 0;
+        }
+
+        trail_heads
+            .into_par_iter()
+            .map(|point| {
+                u32::try_from(
+                    self.find_reachable_target_from_coords(point, target_height)
+                        .len(),
+                )
+                .unwrap()
+            })
+            .sum()
+    }
+
+    fn find_reachable_target_from_coords(
+        &self,
+        point: Point,
+        target_height: u32,
+    ) -> HashSet<Point> {
+        let terrain_height = self.terrain[point];
+        if terrain_height == target_height {
+            return std::iter::once(point).collect();
+        }
+
+        self.uphill_neighbours(point, terrain_height)
+            .into_par_iter()
+            .flat_map(|point| self.find_reachable_target_from_coords(point, target_height))
+            .collect()
+    }
+
+    fn find_all_routes(&self, target_height: u32) -> u32 {
+        let trail_heads: Vec<Point> = self.find_trail_heads();
+        if trail_heads.is_empty() {
+            return 0;
+        }
+
+        trail_heads
+            .into_par_iter()
+            .map(|point| self.find_route_from_coords(point, target_height))
+            .sum()
+    }
+
+    fn find_route_from_coords(&self, point: Point, target_height: u32) -> u32 {
+        let terrain_height = self.terrain[point];
+        if terrain_height == target_height {
+            return 1;
+        }
+
+        self.uphill_neighbours(point, terrain_height)
+            .into_par_iter()
+            .map(|point| self.find_route_from_coords(point, target_height))
+            .sum()
+    }
+
+    fn uphill_neighbours(&self, point: Point, terrain_height: u32) -> Vec<Point> {
+        self.terrain
+            .neighbours4(point)
+            .filter(|&neighbour| self.terrain[neighbour] == terrain_height + 1)
+            .collect()
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = TopographicMap;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let terrain = Grid::parse(input, |c| c.to_digit(10))?;
+
+        Ok(TopographicMap::new(terrain))
+    }
+
+    fn part1(map: &Self::Input) -> anyhow::Result<Self::Part1> {
+        Ok(map.find_reachable_targets(9))
+    }
+
+    fn part2(map: &Self::Input) -> anyhow::Result<Self::Part2> {
+        Ok(map.find_all_routes(9))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn example_part1() {
+        let map = Day10::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day10::part1(&map).unwrap(), 36);
+    }
+
+    #[test]
+    fn example_part2() {
+        let map = Day10::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day10::part2(&map).unwrap(), 81);
+    }
+
+    #[test]
+    fn non_square_grid() {
+        let map = Day10::parse("0123456789\n5555555555\n").unwrap();
+
+        assert_eq!(map.find_reachable_targets(9), 1);
+        assert_eq!(map.find_all_routes(9), 1);
+    }
+
+    #[test]
+    fn real_input() {
+        aoc_core::testing::assert_real_answers::<Day10>(2024, 10);
+    }
+}
@@ -0,0 +1,370 @@
+use std::ops::RangeInclusive;
+
+use aoc_core::{parse, ParseError, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+    /// Strictly increasing or strictly decreasing, whichever the first step goes.
+    Either,
+    /// Never going back against the first non-zero step, equal neighbours are allowed.
+    NonStrict,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Violation {
+    DirectionChange,
+    ZeroStep,
+    StepTooSmall,
+    StepTooLarge,
+}
+
+/// Two neighbouring levels which break the rules of a policy.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BadStep {
+    pub indices: [usize; 2],
+    pub levels: [i32; 2],
+    pub violation: Violation,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnosis {
+    pub safe: bool,
+    /// The first step of the report breaking the rules, before any level is removed.
+    pub first_bad_step: Option<BadStep>,
+    /// Indices of the levels removed to make the report safe.
+    pub removed: Vec<usize>,
+}
+
+/// Rules which decide whether the levels of a report are safe.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    steps: RangeInclusive<u32>,
+    direction: Direction,
+    tolerance: usize,
+}
+
+impl SafetyPolicy {
+    /// Allows steps whose size is within `steps` and going in the given direction. Up to
+    /// `tolerance` levels may be removed to make a report safe.
+    ///
+    /// Equal neighbours are only allowed by [`Direction::NonStrict`] and are not checked against
+    /// the step range.
+    #[must_use]
+    pub const fn new(steps: RangeInclusive<u32>, direction: Direction, tolerance: usize) -> Self {
+        Self {
+            steps,
+            direction,
+            tolerance,
+        }
+    }
+
+    /// Strictly increasing or decreasing reports with steps of 1 to 3 levels.
+    #[must_use]
+    pub const fn strict() -> Self {
+        Self::new(1..=3, Direction::Either, 0)
+    }
+
+    /// The strict rules with the Problem Dampener, which tolerates a single bad level.
+    #[must_use]
+    pub const fn dampened() -> Self {
+        Self::new(1..=3, Direction::Either, 1)
+    }
+
+    #[must_use]
+    pub fn count_safe(&self, reports: &[Vec<i32>]) -> u32 {
+        reports
+            .iter()
+            .map(|report| u32::from(self.is_safe(report)))
+            .sum()
+    }
+
+    #[must_use]
+    pub fn is_safe(&self, report: &[i32]) -> bool {
+        let mut removed = Vec::with_capacity(self.tolerance);
+        self.search(report, &mut removed)
+    }
+
+    /// Explains the verdict for a single report.
+    ///
+    /// The first bad step is looked for in the whole report, going in the direction of its first
+    /// non-zero step unless the policy fixes one. Reports with fewer than two levels are unsafe
+    /// without any bad step.
+    #[must_use]
+    pub fn diagnose(&self, report: &[i32]) -> Diagnosis {
+        let rising = match self.direction {
+            Direction::Increasing => true,
+            Direction::Decreasing => false,
+            Direction::Either | Direction::NonStrict => report
+                .windows(2)
+                .find(|pair| pair[0] != pair[1])
+                .is_none_or(|pair| pair[0] < pair[1]),
+        };
+        let first_bad_step = self.first_violation(report, rising, &[]).err();
+
+        let mut removed = Vec::with_capacity(self.tolerance);
+        let safe = self.search(report, &mut removed);
+        removed.sort_unstable();
+
+        Diagnosis {
+            safe,
+            first_bad_step,
+            removed,
+        }
+    }
+
+    /// Leaves the removed levels in `removed` when the report can be made safe.
+    fn search(&self, report: &[i32], removed: &mut Vec<usize>) -> bool {
+        let orientations: &[bool] = match self.direction {
+            Direction::Increasing => &[true],
+            Direction::Decreasing => &[false],
+            Direction::Either | Direction::NonStrict => &[true, false],
+        };
+
+        orientations
+            .iter()
+            .any(|&rising| self.search_oriented(report, rising, removed, self.tolerance))
+    }
+
+    /// Only the two levels of the first bad step can be the ones whose removal fixes it, so at
+    /// most two candidates are tried per tolerated level instead of every level.
+    fn search_oriented(
+        &self,
+        report: &[i32],
+        rising: bool,
+        removed: &mut Vec<usize>,
+        tolerance: usize,
+    ) -> bool {
+        match self.first_violation(report, rising, removed) {
+            Ok(kept_count) => kept_count >= 2,
+            Err(bad_step) => {
+                tolerance > 0
+                    && bad_step.indices.into_iter().any(|index| {
+                        removed.push(index);
+                        let safe = self.search_oriented(report, rising, removed, tolerance - 1);
+                        if !safe {
+                            removed.pop();
+                        }
+                        safe
+                    })
+            }
+        }
+    }
+
+    /// Counts the kept levels, or finds the first step breaking the rules.
+    fn first_violation(
+        &self,
+        report: &[i32],
+        rising: bool,
+        removed: &[usize],
+    ) -> Result<usize, BadStep> {
+        let mut kept = (0..report.len()).filter(|index| !removed.contains(index));
+        let Some(mut previous) = kept.next() else {
+            return Ok(0);
+        };
+
+        let mut kept_count = 1;
+        for current in kept {
+            let levels = [report[previous], report[current]];
+            let difference = i64::from(levels[1]) - i64::from(levels[0]);
+            if let Err(violation) = self.check_step(rising, difference) {
+                return Err(BadStep {
+                    indices: [previous, current],
+                    levels,
+                    violation,
+                });
+            }
+
+            previous = current;
+            kept_count += 1;
+        }
+
+        Ok(kept_count)
+    }
+
+    fn check_step(&self, rising: bool, difference: i64) -> Result<(), Violation> {
+        if difference == 0 {
+            return if self.direction == Direction::NonStrict {
+                Ok(())
+            } else {
+                Err(Violation::ZeroStep)
+            };
+        }
+        if (difference > 0) != rising {
+            return Err(Violation::DirectionChange);
+        }
+
+        match u32::try_from(difference.unsigned_abs()) {
+            Ok(step) if self.steps.contains(&step) => Ok(()),
+            Ok(step) if step < *self.steps.start() => Err(Violation::StepTooSmall),
+            _ => Err(Violation::StepTooLarge),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    let lines = input.lines();
+
+    lines
+        .map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 2 {
+                return Err(ParseError::at(
+                    input,
+                    &line[line.len()..],
+                    "at least two numbers",
+                ));
+            }
+
+            parts
+                .into_iter()
+                .map(|part| parse::number(input, part))
+                .collect()
+        })
+        .collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(reports: &Self::Input) -> anyhow::Result<Self::Part1> {
+        Ok(SafetyPolicy::strict().count_safe(reports))
+    }
+
+    fn part2(reports: &Self::Input) -> anyhow::Result<Self::Part2> {
+        Ok(SafetyPolicy::dampened().count_safe(reports))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn example_part1() {
+        let reports = Day2::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day2::part1(&reports).unwrap(), 2);
+    }
+
+    #[test]
+    fn example_part2() {
+        let reports = Day2::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day2::part2(&reports).unwrap(), 4);
+    }
+
+    #[test]
+    fn custom_policies() {
+        let report = [1, 2, 2, 6, 5, 10];
+
+        assert!(!SafetyPolicy::new(1..=5, Direction::NonStrict, 0).is_safe(&report));
+        assert!(SafetyPolicy::new(1..=5, Direction::NonStrict, 1).is_safe(&report));
+        assert!(!SafetyPolicy::new(1..=5, Direction::Increasing, 1).is_safe(&report));
+        assert!(SafetyPolicy::new(1..=5, Direction::Increasing, 2).is_safe(&report));
+        assert!(!SafetyPolicy::new(1..=5, Direction::Decreasing, 2).is_safe(&report));
+        assert!(!SafetyPolicy::new(1..=3, Direction::Increasing, 2).is_safe(&report));
+    }
+
+    #[test]
+    fn dampener_matches_trying_every_removal() {
+        fn follows_rules(report: &[i32]) -> bool {
+            let differences: Vec<i32> = report.windows(2).map(|a| a[1] - a[0]).collect();
+            !differences.is_empty()
+                && (differences.iter().all(|&x| (1..4).contains(&x))
+                    || differences.iter().all(|&x| (-3..0).contains(&x)))
+        }
+
+        let mut seed: u32 = 17;
+        let mut next = || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            i32::try_from(seed >> 16).unwrap()
+        };
+
+        for _ in 0..2000 {
+            let len = usize::try_from(next() % 8).unwrap();
+            let report: Vec<i32> = (0..len).map(|_| next() % 9).collect();
+            let expected = follows_rules(&report)
+                || (0..report.len()).any(|skip_index| {
+                    let mut test_report = report.clone();
+                    test_report.remove(skip_index);
+                    follows_rules(&test_report)
+                });
+
+            assert_eq!(
+                SafetyPolicy::dampened().is_safe(&report),
+                expected,
+                "{report:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn diagnoses_example_reports() {
+        let reports = Day2::parse(EXAMPLE).unwrap();
+        let diagnoses: Vec<Diagnosis> = reports
+            .iter()
+            .map(|report| SafetyPolicy::dampened().diagnose(report))
+            .collect();
+
+        assert_eq!(
+            diagnoses[0],
+            Diagnosis {
+                safe: true,
+                first_bad_step: None,
+                removed: vec![],
+            }
+        );
+        assert_eq!(
+            diagnoses[1],
+            Diagnosis {
+                safe: false,
+                first_bad_step: Some(BadStep {
+                    indices: [1, 2],
+                    levels: [2, 7],
+                    violation: Violation::StepTooLarge,
+                }),
+                removed: vec![],
+            }
+        );
+        assert_eq!(
+            diagnoses[3],
+            Diagnosis {
+                safe: true,
+                first_bad_step: Some(BadStep {
+                    indices: [1, 2],
+                    levels: [3, 2],
+                    violation: Violation::DirectionChange,
+                }),
+                removed: vec![1],
+            }
+        );
+        assert_eq!(
+            diagnoses[4].first_bad_step.map(|step| step.violation),
+            Some(Violation::ZeroStep)
+        );
+        assert_eq!(diagnoses[4].removed, vec![2]);
+    }
+
+    #[test]
+    fn real_input() {
+        aoc_core::testing::assert_real_answers::<Day2>(2024, 2);
+    }
+}
@@ -0,0 +1,302 @@
+use std::{borrow::Borrow, error::Error, fmt};
+
+use crate::{
+    expr::{Expr, Language},
+    trace::{Step, StepKind, Toggle, Trace},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    UnknownOperation(String),
+    Arity {
+        name: String,
+        expected: usize,
+        found: usize,
+    },
+    Undefined {
+        name: String,
+        args: Vec<i64>,
+    },
+    Overflow,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownOperation(name) => write!(f, "Unknown operation {name}"),
+            Self::Arity {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Operation {name} takes {expected} operands, but was given {found}"
+            ),
+            Self::Undefined { name, args } => {
+                write!(
+                    f,
+                    "Operation {name} has no i64 result for operands {args:?}"
+                )
+            }
+            Self::Overflow => write!(f, "The sum of the enabled instructions overflows"),
+        }
+    }
+}
+
+impl Error for EvalError {}
+
+pub struct Calculator<'a> {
+    language: &'a Language,
+    enabled_default: bool,
+}
+
+impl<'a> Calculator<'a> {
+    #[must_use]
+    pub const fn new(language: &'a Language) -> Self {
+        Self {
+            language,
+            enabled_default: true,
+        }
+    }
+
+    /// Whether calls count before the first `do()` or `don't()`, which they do by default.
+    #[must_use]
+    pub const fn with_enabled_default(mut self, enabled_default: bool) -> Self {
+        self.enabled_default = enabled_default;
+        self
+    }
+
+    /// Sums up the values of all enabled calls, `do()` and `don't()` toggle the following ones.
+    pub fn exec(
+        &self,
+        expressions: impl IntoIterator<Item = impl Borrow<Expr>>,
+    ) -> Result<i64, EvalError> {
+        self.run(expressions, |_| {})
+    }
+
+    /// Like `exec`, but records every instruction and each `do()` or `don't()` toggle.
+    pub fn trace(
+        &self,
+        expressions: impl IntoIterator<Item = impl Borrow<Expr>>,
+    ) -> Result<Trace, EvalError> {
+        let mut trace = Trace::default();
+        let mut enabled = self.enabled_default;
+
+        trace.total = self.run(expressions, |step| {
+            if matches!(step.kind, StepKind::Do | StepKind::Dont) {
+                trace.toggles.push(Toggle {
+                    instruction: step.instruction,
+                    from: enabled,
+                    to: step.enabled,
+                });
+                enabled = step.enabled;
+            }
+            trace.steps.push(step);
+        })?;
+
+        Ok(trace)
+    }
+
+    fn run(
+        &self,
+        expressions: impl IntoIterator<Item = impl Borrow<Expr>>,
+        mut record: impl FnMut(Step),
+    ) -> Result<i64, EvalError> {
+        let mut enabled = self.enabled_default;
+
+        expressions
+            .into_iter()
+            .enumerate()
+            .try_fold(0_i64, |total, (instruction, expr)| {
+                let expr = expr.borrow();
+                let value = match expr {
+                    Expr::Do => {
+                        enabled = true;
+                        0
+                    }
+                    Expr::Dont => {
+                        enabled = false;
+                        0
+                    }
+                    Expr::Call { .. } if enabled => self.eval(expr)?,
+                    _ => 0,
+                };
+
+                let total = total.checked_add(value).ok_or(EvalError::Overflow)?;
+                record(Step {
+                    instruction,
+                    kind: StepKind::of(expr),
+                    enabled,
+                    value,
+                    total,
+                });
+
+                Ok(total)
+            })
+    }
+
+    pub fn eval(&self, expr: &Expr) -> Result<i64, EvalError> {
+        match expr {
+            Expr::Number(value) => Ok(*value),
+            Expr::Call { name, args } => {
+                let operation = self
+                    .language
+                    .operation(name)
+                    .ok_or_else(|| EvalError::UnknownOperation(name.clone()))?;
+                if args.len() != operation.arity {
+                    return Err(EvalError::Arity {
+                        name: name.clone(),
+                        expected: operation.arity,
+                        found: args.len(),
+                    });
+                }
+                let args = args
+                    .iter()
+                    .map(|arg| self.eval(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                (operation.eval)(&args).ok_or_else(|| EvalError::Undefined {
+                    name: name.clone(),
+                    args,
+                })
+            }
+            Expr::Do | Expr::Dont => Ok(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_input;
+
+    fn exec(language: &Language, input: &str) -> Result<i64, EvalError> {
+        let instructions = parse_input(input, language);
+        Calculator::new(language).exec(instructions.iter().map(|instruction| &instruction.expr))
+    }
+
+    #[test]
+    fn evaluates_nested_calls() {
+        let language = Language::extended();
+
+        assert_eq!(
+            exec(&language, "mul(add(1,-2),3)don't()mul(5,5)do()div(9,2)"),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn registered_operations_are_recognised() {
+        let language =
+            Language::puzzle().with_operation("max", 3, |args| args.iter().max().copied());
+
+        assert_eq!(exec(&language, "max(1,9,4)mul(2,3)min(1,2)"), Ok(15));
+    }
+
+    #[test]
+    fn calls_can_start_disabled() {
+        let language = Language::puzzle();
+        let instructions = parse_input("mul(2,3)do()mul(4,5)", &language);
+        let expressions = instructions.iter().map(|instruction| &instruction.expr);
+
+        assert_eq!(
+            Calculator::new(&language)
+                .with_enabled_default(false)
+                .exec(expressions),
+            Ok(20)
+        );
+    }
+
+    #[test]
+    fn traces_totals_and_toggles() {
+        let language = Language::puzzle();
+        let instructions = parse_input("mul(2,4)don't()mul(5,5)don't()do()mul(8,5)", &language);
+        let trace = Calculator::new(&language)
+            .trace(instructions.iter().map(|instruction| &instruction.expr))
+            .unwrap();
+
+        assert_eq!(trace.total, 48);
+        assert_eq!(
+            trace
+                .steps
+                .iter()
+                .map(|step| (step.enabled, step.value, step.total))
+                .collect::<Vec<_>>(),
+            vec![
+                (true, 8, 8),
+                (false, 0, 8),
+                (false, 0, 8),
+                (false, 0, 8),
+                (true, 0, 8),
+                (true, 40, 48)
+            ]
+        );
+        assert_eq!(
+            trace.toggles,
+            vec![
+                Toggle {
+                    instruction: 1,
+                    from: true,
+                    to: false
+                },
+                Toggle {
+                    instruction: 3,
+                    from: false,
+                    to: false
+                },
+                Toggle {
+                    instruction: 4,
+                    from: false,
+                    to: true
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_overflows() {
+        let language = Language::extended().with_max_operand_digits(None);
+
+        assert_eq!(
+            exec(&language, "mul(9999999999,9999999999)"),
+            Err(EvalError::Undefined {
+                name: "mul".to_string(),
+                args: vec![9_999_999_999, 9_999_999_999]
+            })
+        );
+        assert_eq!(
+            exec(
+                &language,
+                "mul(3037000499,3037000499)mul(3037000499,3037000499)"
+            ),
+            Err(EvalError::Overflow)
+        );
+    }
+
+    #[test]
+    fn checks_the_arity_of_built_calls() {
+        let language = Language::puzzle();
+
+        assert_eq!(
+            Calculator::new(&language).eval(&Expr::call("mul", [Expr::Number(1)])),
+            Err(EvalError::Arity {
+                name: "mul".to_string(),
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn reports_undefined_results() {
+        let language = Language::extended();
+
+        assert_eq!(
+            exec(&language, "div(1,sub(2,2))"),
+            Err(EvalError::Undefined {
+                name: "div".to_string(),
+                args: vec![1, 0]
+            })
+        );
+    }
+}
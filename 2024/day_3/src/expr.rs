@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Do,
+    Dont,
+    Number(i64),
+    Call { name: String, args: Vec<Self> },
+}
+
+impl Expr {
+    pub fn call(name: &str, args: impl IntoIterator<Item = Self>) -> Self {
+        Self::Call {
+            name: name.to_string(),
+            args: args.into_iter().collect(),
+        }
+    }
+}
+
+/// An instruction taking a fixed number of operands, `eval` returns `None` when the result is
+/// undefined, like a division by zero, or does not fit into an `i64`.
+#[derive(Clone, Copy, Debug)]
+pub struct Operation {
+    pub arity: usize,
+    pub eval: fn(&[i64]) -> Option<i64>,
+}
+
+/// How deep calls may be nested in operands unless configured otherwise.
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 8;
+
+/// The instructions recognised in the corrupted memory and the shape of their operands.
+#[derive(Clone, Debug)]
+pub struct Language {
+    operations: HashMap<String, Operation>,
+    signed_operands: bool,
+    nested_calls: bool,
+    max_nesting_depth: usize,
+    max_operand_digits: Option<usize>,
+}
+
+impl Default for Language {
+    fn default() -> Self {
+        Self {
+            operations: HashMap::new(),
+            signed_operands: false,
+            nested_calls: false,
+            max_nesting_depth: DEFAULT_MAX_NESTING_DEPTH,
+            max_operand_digits: None,
+        }
+    }
+}
+
+impl Language {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The original instructions, `mul` of two unsigned numbers with 1 to 3 digits.
+    #[must_use]
+    pub fn puzzle() -> Self {
+        Self::new()
+            .with_operation("mul", 2, |args| args[0].checked_mul(args[1]))
+            .with_max_operand_digits(Some(3))
+    }
+
+    /// Arithmetic on signed operands, which may themselves be calls like `mul(add(1,2),3)`.
+    #[must_use]
+    pub fn extended() -> Self {
+        Self::puzzle()
+            .with_operation("add", 2, |args| args[0].checked_add(args[1]))
+            .with_operation("sub", 2, |args| args[0].checked_sub(args[1]))
+            .with_operation("div", 2, |args| args[0].checked_div(args[1]))
+            .with_signed_operands(true)
+            .with_nested_calls(true)
+    }
+
+    #[must_use]
+    pub fn with_operation(
+        mut self,
+        name: &str,
+        arity: usize,
+        eval: fn(&[i64]) -> Option<i64>,
+    ) -> Self {
+        self.operations
+            .insert(name.to_string(), Operation { arity, eval });
+        self
+    }
+
+    #[must_use]
+    pub const fn with_signed_operands(mut self, signed_operands: bool) -> Self {
+        self.signed_operands = signed_operands;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_nested_calls(mut self, nested_calls: bool) -> Self {
+        self.nested_calls = nested_calls;
+        self
+    }
+
+    /// Limits how many calls may enclose each other within an instruction with nested calls, so
+    /// that `mul(mul(mul(...` in corrupted memory is rejected early.
+    #[must_use]
+    pub const fn with_max_nesting_depth(mut self, max_nesting_depth: usize) -> Self {
+        self.max_nesting_depth = max_nesting_depth;
+        self
+    }
+
+    /// Rejects number operands with more digits, any width is accepted with `None`.
+    #[must_use]
+    pub const fn with_max_operand_digits(mut self, max_operand_digits: Option<usize>) -> Self {
+        self.max_operand_digits = max_operand_digits;
+        self
+    }
+
+    #[must_use]
+    pub fn operation(&self, name: &str) -> Option<&Operation> {
+        self.operations.get(name)
+    }
+
+    pub fn operations(&self) -> impl Iterator<Item = (&str, &Operation)> {
+        self.operations
+            .iter()
+            .map(|(name, operation)| (name.as_str(), operation))
+    }
+
+    #[must_use]
+    pub const fn signed_operands(&self) -> bool {
+        self.signed_operands
+    }
+
+    #[must_use]
+    pub const fn nested_calls(&self) -> bool {
+        self.nested_calls
+    }
+
+    #[must_use]
+    pub const fn max_nesting_depth(&self) -> usize {
+        self.max_nesting_depth
+    }
+
+    #[must_use]
+    pub const fn max_operand_digits(&self) -> Option<usize> {
+        self.max_operand_digits
+    }
+
+    /// The byte length of the longest possible instruction, unbounded with nested calls or
+    /// operands of any width.
+    #[must_use]
+    pub fn max_instruction_len(&self) -> Option<usize> {
+        if self.nested_calls {
+            return None;
+        }
+
+        let operand_len = self.max_operand_digits? + usize::from(self.signed_operands);
+        let longest_call = self
+            .operations
+            .iter()
+            .map(|(name, operation)| {
+                name.len() + 2 + operation.arity * (operand_len + 1) - operation.arity.min(1)
+            })
+            .max()
+            .unwrap_or(0);
+
+        Some(longest_call.max("don't()".len()))
+    }
+}
@@ -0,0 +1,93 @@
+mod calculator;
+mod expr;
+mod parser;
+mod scanner;
+mod trace;
+
+use std::io::Read;
+
+use aoc_core::{ParseError, Solution};
+
+pub use calculator::{Calculator, EvalError};
+pub use expr::{Expr, Language, Operation, DEFAULT_MAX_NESTING_DEPTH};
+pub use parser::{parse_input, Instruction, NearMiss, Parsed, Parser};
+pub use scanner::{Scanner, DEFAULT_LOOKAHEAD};
+pub use trace::{Step, StepKind, Toggle, Trace};
+
+/// Sums up the enabled instructions of a program streamed from `reader`.
+pub fn exec_reader(reader: impl Read, language: &Language) -> anyhow::Result<i64> {
+    let mut read_error = None;
+    let expressions = Scanner::new(reader, language)
+        .map_while(|instruction| instruction.map_err(|err| read_error = Some(err)).ok())
+        .map(|instruction| instruction.expr);
+    let sum = Calculator::new(language).exec(expressions);
+
+    match read_error {
+        Some(err) => Err(err.into()),
+        None => Ok(sum?),
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Instruction>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_input(input, &Language::puzzle()))
+    }
+
+    fn part1(instructions: &Self::Input) -> anyhow::Result<Self::Part1> {
+        let calls = instructions
+            .iter()
+            .map(|instruction| &instruction.expr)
+            .filter(|expr| matches!(expr, Expr::Call { .. }));
+
+        Ok(Calculator::new(&Language::puzzle()).exec(calls)?)
+    }
+
+    fn part2(instructions: &Self::Input) -> anyhow::Result<Self::Part2> {
+        let expressions = instructions.iter().map(|instruction| &instruction.expr);
+
+        Ok(Calculator::new(&Language::puzzle()).exec(expressions)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_PART1: &str =
+        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+    const EXAMPLE_PART2: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    #[test]
+    fn example_part1() {
+        let expressions = Day3::parse(EXAMPLE_PART1).unwrap();
+
+        assert_eq!(Day3::part1(&expressions).unwrap(), 161);
+    }
+
+    #[test]
+    fn example_part2() {
+        let expressions = Day3::parse(EXAMPLE_PART2).unwrap();
+
+        assert_eq!(Day3::part2(&expressions).unwrap(), 48);
+    }
+
+    #[test]
+    fn streams_a_reader() {
+        assert_eq!(
+            exec_reader(EXAMPLE_PART2.as_bytes(), &Language::puzzle()).unwrap(),
+            48
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        aoc_core::testing::assert_real_answers::<Day3>(2024, 3);
+    }
+}
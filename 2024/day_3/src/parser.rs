@@ -0,0 +1,454 @@
+use std::ops::Range;
+
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, digit1, one_of},
+    combinator::{map, opt},
+    error::{ErrorKind, FromExternalError, ParseError},
+    IResult,
+};
+
+use crate::expr::{Expr, Language, Operation};
+
+/// An instruction together with the byte range of the source it was parsed from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub expr: Expr,
+    pub span: Range<usize>,
+}
+
+/// A fragment which starts like an instruction but was rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NearMiss {
+    pub span: Range<usize>,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Parsed {
+    pub instructions: Vec<Instruction>,
+    pub near_misses: Vec<NearMiss>,
+}
+
+/// Where the grammar stopped matching and what it expected there.
+#[derive(Debug)]
+struct Miss<'a> {
+    at: &'a str,
+    /// Byte length of the rejected text, a single character unless given.
+    found_len: Option<usize>,
+    expected: String,
+}
+
+impl<'a> Miss<'a> {
+    fn new(at: &'a str, expected: impl Into<String>) -> Self {
+        Self {
+            at,
+            found_len: None,
+            expected: expected.into(),
+        }
+    }
+
+    fn spanning(at: &'a str, found_len: usize, expected: impl Into<String>) -> Self {
+        Self {
+            found_len: Some(found_len),
+            ..Self::new(at, expected)
+        }
+    }
+}
+
+impl<'a> ParseError<&'a str> for Miss<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Self::new(input, kind.description())
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn or(self, other: Self) -> Self {
+        if other.at.len() < self.at.len() {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Miss<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+        Self::from_error_kind(input, kind)
+    }
+}
+
+type PResult<'a, T> = IResult<&'a str, T, Miss<'a>>;
+
+fn expect<'a, T>(
+    mut parser: impl FnMut(&'a str) -> PResult<'a, T>,
+    expected: &'static str,
+) -> impl FnMut(&'a str) -> PResult<'a, T> {
+    move |input| parser(input).map_err(|err| err.map(|_| Miss::new(input, expected)))
+}
+
+fn parse_integer<'a>(input: &'a str, language: &Language) -> PResult<'a, i64> {
+    let (unsigned, _) = if language.signed_operands() {
+        opt(char('-'))(input)?
+    } else {
+        (input, None)
+    };
+    let (rest, digits) = expect(digit1, "an operand")(unsigned)?;
+
+    if let Some(max) = language
+        .max_operand_digits()
+        .filter(|&max| digits.len() > max)
+    {
+        return Err(nom::Err::Error(Miss::spanning(
+            unsigned,
+            digits.len(),
+            format!("an operand of at most {max} digits"),
+        )));
+    }
+
+    let value = input[..input.len() - rest.len()].parse().map_err(|_| {
+        nom::Err::Error(Miss::spanning(
+            unsigned,
+            digits.len(),
+            "an operand which fits into 64 bits",
+        ))
+    })?;
+
+    Ok((rest, value))
+}
+
+/// Parses an operand of a call which is nested `depth` calls deep.
+fn parse_operand<'a>(input: &'a str, language: &Language, depth: usize) -> PResult<'a, Expr> {
+    if language.nested_calls() && depth >= language.max_nesting_depth() {
+        if operation_at(input, language).is_some() {
+            return Err(nom::Err::Error(Miss::new(
+                input,
+                format!(
+                    "a number, calls may only be nested {} deep",
+                    language.max_nesting_depth()
+                ),
+            )));
+        }
+    } else if language.nested_calls() {
+        match parse_call(input, language, depth + 1) {
+            Ok(result) => return Ok(result),
+            Err(nom::Err::Error(miss)) if miss.at.len() < input.len() => {
+                return Err(nom::Err::Error(miss));
+            }
+            Err(_) => {}
+        }
+    }
+
+    map(|input| parse_integer(input, language), Expr::Number)(input)
+}
+
+/// The registered operation whose name starts the input. The longest name wins, so that `mult(`
+/// is not read as `mul` when both exist.
+fn operation_at<'l>(input: &str, language: &'l Language) -> Option<(&'l str, &'l Operation)> {
+    language
+        .operations()
+        .filter(|(name, _)| input.starts_with(name))
+        .max_by_key(|(name, _)| name.len())
+}
+
+/// Parses a call which is nested in `depth` other calls.
+fn parse_call<'a>(input: &'a str, language: &Language, depth: usize) -> PResult<'a, Expr> {
+    let Some((name, operation)) = operation_at(input, language) else {
+        return Err(nom::Err::Error(Miss::new(input, "an instruction")));
+    };
+    let rest = &input[name.len()..];
+
+    let (mut rest, _) = expect(char('('), "`(`")(rest)?;
+    let mut args = vec![];
+    let mut closing = rest;
+    if char::<_, Miss>(')')(rest).is_err() {
+        loop {
+            let (after, arg) = parse_operand(rest, language, depth)?;
+            args.push(arg);
+
+            closing = after;
+            let (after, separator) = expect(one_of(",)"), "`,` or `)`")(after)?;
+            rest = after;
+            if separator == ')' {
+                break;
+            }
+        }
+    } else {
+        rest = &rest[1..];
+    }
+
+    if args.len() != operation.arity {
+        return Err(nom::Err::Error(Miss::new(
+            closing,
+            format!("{} operands", operation.arity),
+        )));
+    }
+
+    Ok((rest, Expr::call(name, args)))
+}
+
+fn parse_do(input: &str) -> PResult<'_, Expr> {
+    map(tag("do()"), |_| Expr::Do)(input)
+}
+
+fn parse_dont(input: &str) -> PResult<'_, Expr> {
+    map(tag("don't()"), |_| Expr::Dont)(input)
+}
+
+fn parse_expr<'a>(input: &'a str, language: &Language) -> PResult<'a, Expr> {
+    alt((parse_do, parse_dont, |input| parse_call(input, language, 0)))(input)
+}
+
+/// Parses the instruction at the start of `input`, returning it with the text after it.
+pub fn parse_instruction<'a>(input: &'a str, language: &Language) -> Option<(&'a str, Expr)> {
+    parse_expr(input, language).ok()
+}
+
+/// Scans corrupted memory for the instructions of a language, skipping everything else.
+pub struct Parser<'a> {
+    language: &'a Language,
+    near_misses: bool,
+}
+
+impl<'a> Parser<'a> {
+    #[must_use]
+    pub const fn new(language: &'a Language) -> Self {
+        Self {
+            language,
+            near_misses: false,
+        }
+    }
+
+    /// Also reports the fragments which start with a known instruction name but do not parse.
+    #[must_use]
+    pub const fn with_near_misses(mut self, near_misses: bool) -> Self {
+        self.near_misses = near_misses;
+        self
+    }
+
+    #[must_use]
+    pub fn parse(&self, input: &str) -> Parsed {
+        let mut parsed = Parsed::default();
+
+        let mut offset = 0;
+        while let Some(rest) = input.get(offset..).filter(|rest| !rest.is_empty()) {
+            match parse_expr(rest, self.language) {
+                Ok((remaining, expr)) => {
+                    let end = input.len() - remaining.len();
+                    parsed.instructions.push(Instruction {
+                        expr,
+                        span: offset..end,
+                    });
+                    offset = end;
+                }
+                Err(err) => {
+                    if let nom::Err::Error(miss) | nom::Err::Failure(miss) = err {
+                        if self.near_misses && miss.at.len() < rest.len() {
+                            parsed
+                                .near_misses
+                                .push(Self::near_miss(input, offset, &miss));
+                        }
+                    }
+                    offset += rest.chars().next().map_or(1, char::len_utf8);
+                }
+            }
+        }
+
+        parsed
+    }
+
+    fn near_miss(input: &str, offset: usize, miss: &Miss) -> NearMiss {
+        let at = input.len() - miss.at.len();
+        let found_len = miss
+            .found_len
+            .unwrap_or_else(|| miss.at.chars().next().map_or(0, char::len_utf8));
+        let end = at + found_len;
+        let found_text = if found_len == 0 {
+            "end of input".to_string()
+        } else {
+            format!("`{}`", &input[at..end])
+        };
+
+        NearMiss {
+            span: offset..end,
+            reason: format!("expected {}, found {found_text}", miss.expected),
+        }
+    }
+}
+
+#[must_use]
+pub fn parse_input(input: &str, language: &Language) -> Vec<Instruction> {
+    Parser::new(language).parse(input).instructions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mul(x: i64, y: i64) -> Expr {
+        Expr::call("mul", [Expr::Number(x), Expr::Number(y)])
+    }
+
+    fn exprs(instructions: Vec<Instruction>) -> Vec<Expr> {
+        instructions
+            .into_iter()
+            .map(|instruction| instruction.expr)
+            .collect()
+    }
+
+    #[test]
+    fn parser_skips_corrupted_instructions() {
+        let instructions = parse_input(
+            "mul(4*mul(6,9!?(12,34)mul ( 2 , 4 )mul(3,4)",
+            &Language::puzzle(),
+        );
+
+        assert_eq!(exprs(instructions), vec![mul(3, 4)]);
+    }
+
+    #[test]
+    fn puzzle_language_ignores_extensions() {
+        let instructions = parse_input(
+            "add(1,2)mul(-1,2)mul(mul(2,3),4)mul(1,2,3)",
+            &Language::puzzle(),
+        );
+
+        assert_eq!(exprs(instructions), vec![mul(2, 3)]);
+    }
+
+    #[test]
+    fn parses_nested_calls_with_signed_operands() {
+        let instructions = parse_input(
+            "xmul(add(1,-2),3)don't()div(sub(7,1),mul(1,-3))mul(1,2,3)",
+            &Language::extended(),
+        );
+
+        assert_eq!(
+            exprs(instructions),
+            vec![
+                Expr::call(
+                    "mul",
+                    [
+                        Expr::call("add", [Expr::Number(1), Expr::Number(-2)]),
+                        Expr::Number(3)
+                    ]
+                ),
+                Expr::Dont,
+                Expr::call(
+                    "div",
+                    [
+                        Expr::call("sub", [Expr::Number(7), Expr::Number(1)]),
+                        mul(1, -3)
+                    ]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn prefers_the_longest_operation_name() {
+        let language = Language::puzzle().with_operation("mult", 1, |args| Some(args[0]));
+        let instructions = parse_input("xmult(5)amul(2,3)multi(1)", &language);
+
+        assert_eq!(
+            exprs(instructions),
+            vec![Expr::call("mult", [Expr::Number(5)]), mul(2, 3)]
+        );
+    }
+
+    #[test]
+    fn scans_long_identifier_runs_once() {
+        let input = format!("{}mul(2,3)", "a".repeat(200_000));
+
+        assert_eq!(
+            exprs(parse_input(&input, &Language::puzzle())),
+            vec![mul(2, 3)]
+        );
+    }
+
+    #[test]
+    fn limits_the_nesting_depth() {
+        let input = format!("{}mul(2,3)", "mul(".repeat(20_000));
+
+        assert_eq!(
+            exprs(parse_input(&input, &Language::extended())),
+            vec![mul(2, 3)]
+        );
+
+        let language = Language::extended().with_max_nesting_depth(1);
+        assert_eq!(
+            exprs(parse_input(
+                "mul(add(1,2),3)mul(add(sub(1,2),3),4)",
+                &language
+            )),
+            vec![
+                Expr::call(
+                    "mul",
+                    [
+                        Expr::call("add", [Expr::Number(1), Expr::Number(2)]),
+                        Expr::Number(3)
+                    ]
+                ),
+                Expr::call(
+                    "add",
+                    [
+                        Expr::call("sub", [Expr::Number(1), Expr::Number(2)]),
+                        Expr::Number(3)
+                    ]
+                )
+            ]
+        );
+
+        let near_misses = Parser::new(&language)
+            .with_near_misses(true)
+            .parse("mul(add(sub(1,2),3),4)")
+            .near_misses;
+        assert_eq!(
+            near_misses[0].reason,
+            "expected a number, calls may only be nested 1 deep, found `s`"
+        );
+    }
+
+    #[test]
+    fn instructions_keep_their_byte_ranges() {
+        let input = "xmul(2,4)é&don't()_mul(5,5)";
+        let spans: Vec<&str> = parse_input(input, &Language::puzzle())
+            .into_iter()
+            .map(|instruction| &input[instruction.span])
+            .collect();
+
+        assert_eq!(spans, vec!["mul(2,4)", "don't()", "mul(5,5)"]);
+    }
+
+    #[test]
+    fn reports_near_misses() {
+        let input = "mul(4*mul ( 2 , 4 )mul(3,4)mul(1,2,3)mul(1234,5)mul(2,";
+        let near_misses: Vec<(&str, String)> = Parser::new(&Language::puzzle())
+            .with_near_misses(true)
+            .parse(input)
+            .near_misses
+            .into_iter()
+            .map(|near_miss| (&input[near_miss.span], near_miss.reason))
+            .collect();
+
+        assert_eq!(
+            near_misses,
+            vec![
+                ("mul(4*", "expected `,` or `)`, found `*`".to_string()),
+                ("mul ", "expected `(`, found ` `".to_string()),
+                ("mul(1,2,3)", "expected 2 operands, found `)`".to_string()),
+                (
+                    "mul(1234",
+                    "expected an operand of at most 3 digits, found `1234`".to_string()
+                ),
+                (
+                    "mul(2,",
+                    "expected an operand, found end of input".to_string()
+                ),
+            ]
+        );
+    }
+}
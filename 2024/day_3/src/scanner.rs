@@ -0,0 +1,257 @@
+use std::{
+    collections::VecDeque,
+    io::{self, ErrorKind, Read},
+    mem, str,
+};
+
+use crate::{
+    expr::Language,
+    parser::{parse_instruction, Instruction},
+};
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// How far ahead an instruction may reach when the language does not bound its length.
+pub const DEFAULT_LOOKAHEAD: usize = 64 * 1024;
+
+/// Parses the instructions of a language lazily from a reader, holding at most a chunk and the
+/// lookahead in memory.
+///
+/// A position is only tried once `lookahead` bytes follow it, and only those bytes are parsed.
+/// So every instruction up to that length is found even when it straddles two chunks, longer ones
+/// are never found. Spans are byte offsets into the whole
+/// stream, bytes which are not valid UTF-8 are skipped like any other corruption.
+pub struct Scanner<'a, R> {
+    reader: R,
+    language: &'a Language,
+    lookahead: usize,
+    chunk_size: usize,
+    /// The valid UTF-8 read so far, each byte is validated once when it is read.
+    text: String,
+    /// Index into `text` of the first byte not scanned yet.
+    start: usize,
+    /// Bytes of `text` dropped in front of it.
+    consumed: usize,
+    /// Invalid bytes skipped before `start`.
+    skipped: usize,
+    /// Runs of invalid bytes left out of `text`, by the position in the text read so far which
+    /// they precede and their length. No instruction spans them.
+    gaps: VecDeque<(usize, usize)>,
+    /// The start of a char which was cut off at the end of the last read.
+    incomplete: Vec<u8>,
+    eof: bool,
+}
+
+impl<'a, R: Read> Scanner<'a, R> {
+    #[must_use]
+    pub fn new(reader: R, language: &'a Language) -> Self {
+        Self {
+            reader,
+            language,
+            lookahead: language.max_instruction_len().unwrap_or(DEFAULT_LOOKAHEAD),
+            chunk_size: CHUNK_SIZE,
+            text: String::new(),
+            start: 0,
+            consumed: 0,
+            skipped: 0,
+            gaps: VecDeque::new(),
+            incomplete: vec![],
+            eof: false,
+        }
+    }
+
+    /// Instructions longer than the lookahead are not found.
+    #[must_use]
+    pub fn with_lookahead(mut self, lookahead: usize) -> Self {
+        self.lookahead = lookahead.max(1);
+        self
+    }
+
+    #[must_use]
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Scans the buffered text, returning the next instruction or consuming everything which can
+    /// no longer start one.
+    fn scan(&mut self) -> Option<Instruction> {
+        loop {
+            let gap = self.gaps.front().map(|&(at, _)| at - self.consumed);
+            let complete = self.eof || gap.is_some();
+            let text = &self.text[self.start..gap.unwrap_or(self.text.len())];
+            let stream_start = self.consumed + self.skipped + self.start;
+
+            let mut position = 0;
+            while position < text.len() && (complete || text.len() - position >= self.lookahead) {
+                let rest = &text[position..];
+                let window = &rest[..rest.floor_char_boundary(self.lookahead)];
+                if let Some((remaining, expr)) = parse_instruction(window, self.language) {
+                    let end = position + window.len() - remaining.len();
+                    self.start += end;
+
+                    return Some(Instruction {
+                        expr,
+                        span: stream_start + position..stream_start + end,
+                    });
+                }
+
+                position += rest.chars().next().map_or(1, char::len_utf8);
+            }
+            self.start += position;
+
+            if position < text.len() || gap.is_none() {
+                return None;
+            }
+            while let Some(&(_, len)) = self
+                .gaps
+                .front()
+                .filter(|&&(at, _)| at == self.consumed + self.start)
+            {
+                self.skipped += len;
+                self.gaps.pop_front();
+            }
+        }
+    }
+
+    fn fill(&mut self) -> io::Result<()> {
+        self.text.drain(..self.start);
+        self.consumed += self.start;
+        self.start = 0;
+
+        let mut bytes = mem::take(&mut self.incomplete);
+        let len = bytes.len();
+        bytes.resize(len + self.chunk_size, 0);
+        let read = loop {
+            match self.reader.read(&mut bytes[len..]) {
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                result => break result,
+            }
+        };
+        let read = match read {
+            Ok(read) => read,
+            Err(err) => {
+                bytes.truncate(len);
+                self.incomplete = bytes;
+                return Err(err);
+            }
+        };
+        bytes.truncate(len + read);
+        self.eof = read == 0;
+
+        let mut validated = 0;
+        for chunk in bytes.utf8_chunks() {
+            self.text.push_str(chunk.valid());
+            validated += chunk.valid().len();
+
+            let invalid = chunk.invalid();
+            let at_end = validated + invalid.len() == bytes.len();
+            let cut_off = str::from_utf8(invalid).is_err_and(|err| err.error_len().is_none());
+            if at_end && cut_off && !self.eof {
+                break;
+            }
+            if !invalid.is_empty() {
+                self.gaps
+                    .push_back((self.consumed + self.text.len(), invalid.len()));
+            }
+            validated += invalid.len();
+        }
+        bytes.drain(..validated);
+        self.incomplete = bytes;
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for Scanner<'_, R> {
+    type Item = io::Result<Instruction>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(instruction) = self.scan() {
+                return Some(Ok(instruction));
+            }
+            if self.eof {
+                return None;
+            }
+            if let Err(err) = self.fill() {
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{expr::Expr, parser::parse_input};
+
+    fn scan(input: &[u8], language: &Language, chunk_size: usize) -> Vec<Instruction> {
+        Scanner::new(input, language)
+            .with_chunk_size(chunk_size)
+            .collect::<io::Result<_>>()
+            .unwrap()
+    }
+
+    fn exprs(instructions: Vec<Instruction>) -> Vec<Expr> {
+        instructions
+            .into_iter()
+            .map(|instruction| instruction.expr)
+            .collect()
+    }
+
+    #[test]
+    fn finds_instructions_straddling_chunks() {
+        let input = "xmul(2,4)%&mul[3,7]!@^don't()_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+        for chunk_size in 1..=input.len() {
+            assert_eq!(
+                scan(input.as_bytes(), &Language::puzzle(), chunk_size),
+                parse_input(input, &Language::puzzle()),
+                "chunk size {chunk_size}"
+            );
+        }
+    }
+
+    #[test]
+    fn finds_nested_calls_within_the_lookahead() {
+        let input = "mul(add(1,-2),3)é!do()div(sub(7,1),mul(1,-3))";
+
+        for chunk_size in [1, 2, 3, 5, 8] {
+            assert_eq!(
+                scan(input.as_bytes(), &Language::extended(), chunk_size),
+                parse_input(input, &Language::extended())
+            );
+        }
+    }
+
+    #[test]
+    fn streams_long_runs_of_unclosed_calls() {
+        let input = format!("{}mul(2,3)", "mul(".repeat(20_000));
+
+        assert_eq!(
+            exprs(scan(input.as_bytes(), &Language::extended(), CHUNK_SIZE)),
+            vec![Expr::call("mul", [Expr::Number(2), Expr::Number(3)])]
+        );
+    }
+
+    #[test]
+    fn skips_invalid_utf8() {
+        let mut input = b"mul(1,2)\xff\xfemul(3,4)\xe2\x82mul(5,6)".to_vec();
+        input.extend("€mul(7,8)".as_bytes());
+        input.push(0xc3);
+
+        for chunk_size in 1..=input.len() {
+            let spans: Vec<_> = scan(&input, &Language::puzzle(), chunk_size)
+                .into_iter()
+                .map(|instruction| instruction.span)
+                .collect();
+
+            assert_eq!(
+                spans,
+                vec![0..8, 10..18, 20..28, 31..39],
+                "chunk size {chunk_size}"
+            );
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::fmt::Write;
+
+use crate::expr::Expr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StepKind {
+    Do,
+    Dont,
+    Call(String),
+    Number,
+}
+
+impl StepKind {
+    #[must_use]
+    pub fn of(expr: &Expr) -> Self {
+        match expr {
+            Expr::Do => Self::Do,
+            Expr::Dont => Self::Dont,
+            Expr::Call { name, .. } => Self::Call(name.clone()),
+            Expr::Number(_) => Self::Number,
+        }
+    }
+
+    /// The instruction as written, calls by their name.
+    #[must_use]
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Do => "do",
+            Self::Dont => "don't",
+            Self::Call(name) => name,
+            Self::Number => "number",
+        }
+    }
+}
+
+/// One executed instruction, `enabled` is the state after a toggle took effect.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    /// Position in the executed instructions.
+    pub instruction: usize,
+    pub kind: StepKind,
+    pub enabled: bool,
+    /// What the instruction added to the total.
+    pub value: i64,
+    pub total: i64,
+}
+
+/// A `do()` or `don't()`, which leaves the state unchanged when it was already set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Toggle {
+    pub instruction: usize,
+    pub from: bool,
+    pub to: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub steps: Vec<Step>,
+    pub toggles: Vec<Toggle>,
+    pub total: i64,
+}
+
+impl Trace {
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let steps = self
+            .steps
+            .iter()
+            .map(|step| {
+                format!(
+                    r#"{{"instruction":{},"kind":{},"enabled":{},"value":{},"total":{}}}"#,
+                    step.instruction,
+                    json_string(step.kind.label()),
+                    step.enabled,
+                    step.value,
+                    step.total
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let toggles = self
+            .toggles
+            .iter()
+            .map(|toggle| {
+                format!(
+                    r#"{{"instruction":{},"from":{},"to":{}}}"#,
+                    toggle.instruction, toggle.from, toggle.to
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!(
+            r#"{{"total":{},"steps":[{steps}],"toggles":[{toggles}]}}"#,
+            self.total
+        )
+    }
+
+    /// One row per step, toggles show up as the change of `enabled` between rows.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("instruction,kind,enabled,value,total\n");
+        for step in &self.steps {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{}",
+                step.instruction,
+                csv_field(step.kind.label()),
+                step.enabled,
+                step.value,
+                step.total
+            );
+        }
+
+        csv
+    }
+}
+
+/// Quotes the text as a JSON string, since operation names are chosen by users.
+fn json_string(text: &str) -> String {
+    let mut json = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", u32::from(c));
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Quotes the field when it contains a separator, a quote or a line break.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace() -> Trace {
+        Trace {
+            steps: vec![
+                Step {
+                    instruction: 0,
+                    kind: StepKind::Call("mul".to_string()),
+                    enabled: true,
+                    value: 8,
+                    total: 8,
+                },
+                Step {
+                    instruction: 1,
+                    kind: StepKind::Dont,
+                    enabled: false,
+                    value: 0,
+                    total: 8,
+                },
+            ],
+            toggles: vec![Toggle {
+                instruction: 1,
+                from: true,
+                to: false,
+            }],
+            total: 8,
+        }
+    }
+
+    #[test]
+    fn exports_json() {
+        assert_eq!(
+            trace().to_json(),
+            r#"{"total":8,"steps":[{"instruction":0,"kind":"mul","enabled":true,"value":8,"total":8},{"instruction":1,"kind":"don't","enabled":false,"value":0,"total":8}],"toggles":[{"instruction":1,"from":true,"to":false}]}"#
+        );
+    }
+
+    #[test]
+    fn exports_csv() {
+        assert_eq!(
+            trace().to_csv(),
+            "instruction,kind,enabled,value,total\n0,mul,true,8,8\n1,don't,false,0,8\n"
+        );
+    }
+
+    #[test]
+    fn escapes_operation_names() {
+        let mut trace = trace();
+        trace.steps[0].kind = StepKind::Call("a,\"b\\\n".to_string());
+
+        assert!(trace.to_json().contains(r#""kind":"a,\"b\\\n","enabled""#));
+        assert!(trace.to_csv().contains("0,\"a,\"\"b\\\n\",true,8,8\n"));
+    }
+}
@@ -0,0 +1,173 @@
+mod render;
+mod stencil;
+mod words;
+
+use std::sync::LazyLock;
+
+use aoc_core::{ParseError, Solution};
+use grid::{Grid, Point};
+
+pub use stencil::{Stencil, StencilMatch, Symmetry, WILDCARD};
+pub use words::WordMatch;
+
+/// Two `MAS` crossing on their `A`, each read forwards or backwards.
+const X_MAS_CONFIG: &str = "[x-mas]
+symmetry = rotations
+M.S
+.A.
+M.S
+";
+
+static X_MAS: LazyLock<Stencil> = LazyLock::new(|| {
+    Stencil::parse_config(X_MAS_CONFIG)
+        .expect("The X-MAS stencil must parse")
+        .remove(0)
+});
+
+pub struct WordSearch {
+    grid: Grid<char>,
+}
+
+impl WordSearch {
+    const fn new(grid: Grid<char>) -> Self {
+        Self { grid }
+    }
+
+    #[must_use]
+    pub fn find_x_shaped_xmas(&self) -> Vec<StencilMatch> {
+        self.find_stencil(&X_MAS)
+    }
+
+    /// Finds the word in all 8 directions.
+    #[must_use]
+    pub fn find_string(&self, text: &str) -> Vec<WordMatch> {
+        let mut result = vec![];
+
+        for (point, &c) in self.grid.iter() {
+            if text.starts_with(c) {
+                for direction in Point::ALL_DIRECTIONS {
+                    if self.find_word_dir(text, point, direction) {
+                        result.push(WordMatch {
+                            start: point,
+                            direction,
+                            word: 0,
+                            len: text.chars().count(),
+                        });
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Matches the word char by char, so that cells and word agree on non-ASCII text.
+    fn find_word_dir(&self, text: &str, point: Point, direction: Point) -> bool {
+        let mut chars = text.chars();
+        let Some(first) = chars.next() else {
+            return true;
+        };
+
+        if self.get_char(point) == Some(first) {
+            return self.find_word_dir(chars.as_str(), point + direction, direction);
+        }
+
+        false
+    }
+
+    fn get_char(&self, point: Point) -> Option<char> {
+        self.grid.get(point).copied()
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = WordSearch;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(WordSearch::new(Grid::parse(input, Some)?))
+    }
+
+    fn part1(word_search: &Self::Input) -> anyhow::Result<Self::Part1> {
+        Ok(word_search.find_string("XMAS").len())
+    }
+
+    fn part2(word_search: &Self::Input) -> anyhow::Result<Self::Part2> {
+        Ok(word_search.find_x_shaped_xmas().len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn example_part1() {
+        let word_search = Day4::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day4::part1(&word_search).unwrap(), 18);
+    }
+
+    #[test]
+    fn example_part2() {
+        let word_search = Day4::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day4::part2(&word_search).unwrap(), 9);
+    }
+
+    #[test]
+    fn non_square_grid() {
+        let word_search = Day4::parse("XMASAMX.\nM.S...M.\n.A....A.\nM.S...S.\n").unwrap();
+
+        assert_eq!(word_search.find_string("XMAS").len(), 3);
+        assert_eq!(word_search.find_x_shaped_xmas().len(), 1);
+    }
+
+    #[test]
+    fn accented_grid() {
+        let word_search = Day4::parse("ÉTÉ.\nTÉ..\nÉ.é.\n").unwrap();
+
+        assert_eq!(word_search.find_string("ÉTÉ").len(), 4);
+        assert_eq!(word_search.find_string("ÉTé").len(), 0);
+        assert_eq!(
+            word_search.find_words(&["ÉTÉ", "TÉ"]).len(),
+            word_search.find_string("ÉTÉ").len() + word_search.find_string("TÉ").len()
+        );
+    }
+
+    #[test]
+    fn cjk_grid() {
+        let input = "日本語.\n本本..\n語.語.\n";
+        let word_search = Day4::parse(input).unwrap();
+        let matches = word_search.find_string("日本語");
+
+        assert_eq!(matches.len(), 3);
+        assert_eq!(
+            word_search.render(matches.iter().flat_map(WordMatch::cells)),
+            "日本語.\n本本..\n語.語.\n"
+        );
+
+        let stencil = Stencil::new("corner", "日本\n本.", Symmetry::None).unwrap();
+        assert_eq!(word_search.find_stencil(&stencil).len(), 1);
+    }
+
+    #[test]
+    fn real_input() {
+        aoc_core::testing::assert_real_answers::<Day4>(2024, 4);
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use grid::Point;
+
+use crate::WordSearch;
+
+const HIGHLIGHT: &str = "\x1b[1;33m";
+const RESET: &str = "\x1b[0m";
+
+impl WordSearch {
+    /// Prints the grid like the puzzle's illustrations, replacing every cell which is not part of
+    /// a match by `.`.
+    #[must_use]
+    pub fn render(&self, matched: impl IntoIterator<Item = Point>) -> String {
+        self.render_with(matched, false)
+    }
+
+    /// Like `render`, with the matched cells highlighted by ANSI colour codes.
+    #[must_use]
+    pub fn render_coloured(&self, matched: impl IntoIterator<Item = Point>) -> String {
+        self.render_with(matched, true)
+    }
+
+    fn render_with(&self, matched: impl IntoIterator<Item = Point>, coloured: bool) -> String {
+        let matched: HashSet<Point> = matched.into_iter().collect();
+        let mut rendered = String::new();
+
+        for row in self.grid.rows() {
+            for (point, &c) in row {
+                if !matched.contains(&point) {
+                    rendered.push('.');
+                } else if coloured {
+                    rendered.push_str(HIGHLIGHT);
+                    rendered.push(c);
+                    rendered.push_str(RESET);
+                } else {
+                    rendered.push(c);
+                }
+            }
+            rendered.push('\n');
+        }
+
+        rendered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use crate::{Day4, WordMatch};
+
+    const EXAMPLE: &str = "..X...
+.SAMX.
+.A..A.
+XMAS.S
+.X....
+";
+
+    #[test]
+    fn renders_only_the_matched_cells() {
+        let word_search = Day4::parse(EXAMPLE).unwrap();
+        let matches = word_search.find_string("XMAS");
+
+        assert_eq!(matches.len(), 4);
+        assert_eq!(
+            word_search.render(matches.iter().flat_map(WordMatch::cells)),
+            "..X...
+.SAMX.
+.A..A.
+XMAS.S
+.X....
+"
+        );
+    }
+
+    #[test]
+    fn highlights_matches_in_colour() {
+        let word_search = Day4::parse("XMAS\nABCD\n").unwrap();
+        let matches = word_search.find_string("XMAS");
+
+        assert_eq!(
+            word_search.render_coloured(matches.iter().flat_map(WordMatch::cells)),
+            "\x1b[1;33mX\x1b[0m\x1b[1;33mM\x1b[0m\x1b[1;33mA\x1b[0m\x1b[1;33mS\x1b[0m\n....\n"
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use aoc_core::ParseError;
+use grid::{Grid, Point};
+
+use crate::WordSearch;
+
+/// A placement of a stencil, `variant` tells its orientations apart.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StencilMatch {
+    /// The top-left corner of the placed template.
+    pub anchor: Point,
+    pub variant: usize,
+    /// The matched cells, leaving out the wildcards.
+    pub cells: Vec<Point>,
+}
+
+/// Marks a template cell which matches any grid cell.
+pub const WILDCARD: char = '.';
+
+/// The orientations of a template which are matched besides the template itself.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Symmetry {
+    #[default]
+    None,
+    /// Rotations by 90, 180 and 270 degrees.
+    Rotations,
+    /// Rotations and mirror images.
+    Reflections,
+}
+
+impl Symmetry {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "none" => Some(Self::None),
+            "rotations" => Some(Self::Rotations),
+            "reflections" => Some(Self::Reflections),
+            _ => None,
+        }
+    }
+}
+
+/// A small 2D template of chars and wildcards, matched anywhere in a word search.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stencil {
+    name: String,
+    /// Every distinct orientation as the sorted non-wildcard cells, offset from the top-left
+    /// corner of the template.
+    variants: Vec<Vec<(Point, char)>>,
+}
+
+impl Stencil {
+    /// Parses a template of equally wide rows, where `.` is a wildcard.
+    pub fn new(name: &str, template: &str, symmetry: Symmetry) -> Result<Self, ParseError> {
+        let grid = Grid::parse(template, |c| Some((c != WILDCARD).then_some(c)))?;
+        let cells: Vec<(Point, char)> = grid
+            .iter()
+            .filter_map(|(point, &cell)| cell.map(|c| (point, c)))
+            .collect();
+        if cells.is_empty() {
+            return Err(ParseError::at(
+                template,
+                template,
+                format!("a template cell other than the `{WILDCARD}` wildcard"),
+            ));
+        }
+
+        let rotations = match symmetry {
+            Symmetry::None => 1,
+            Symmetry::Rotations | Symmetry::Reflections => 4,
+        };
+        let mirrors: &[bool] = match symmetry {
+            Symmetry::Reflections => &[false, true],
+            _ => &[false],
+        };
+
+        let mut variants = vec![];
+        for &mirrored in mirrors {
+            let mut variant: Vec<(Point, char)> = cells
+                .iter()
+                .map(|&(point, c)| {
+                    let point = if mirrored {
+                        Point::new(point.row, -point.col)
+                    } else {
+                        point
+                    };
+                    (point, c)
+                })
+                .collect();
+
+            for _ in 0..rotations {
+                let normalized = normalize(&variant);
+                if !variants.contains(&normalized) {
+                    variants.push(normalized);
+                }
+                for (point, _) in &mut variant {
+                    *point = point.turn_right();
+                }
+            }
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            variants,
+        })
+    }
+
+    /// Parses stencils from a config of `[name]` sections. Each section may start with a
+    /// `symmetry = none | rotations | reflections` line, followed by the template rows. Blank lines
+    /// and lines starting with `#` are ignored.
+    pub fn parse_config(config: &str) -> Result<Vec<Self>, ParseError> {
+        let mut sections: Vec<(&str, Symmetry, Vec<&str>)> = vec![];
+
+        for line in config.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = trimmed.strip_prefix('[') {
+                let name = header
+                    .strip_suffix(']')
+                    .filter(|name| !name.is_empty())
+                    .ok_or_else(|| ParseError::at(config, trimmed, "a `[name]` header"))?;
+                sections.push((name, Symmetry::None, vec![]));
+                continue;
+            }
+
+            let Some((_, symmetry, rows)) = sections.last_mut() else {
+                return Err(ParseError::at(config, trimmed, "a `[name]` header"));
+            };
+            if let Some((key, value)) = trimmed.split_once('=') {
+                if key.trim() != "symmetry" || !rows.is_empty() {
+                    return Err(ParseError::at(config, trimmed, "a template row"));
+                }
+                let value = value.trim();
+                *symmetry = Symmetry::from_name(value).ok_or_else(|| {
+                    ParseError::at(config, value, "`none`, `rotations` or `reflections`")
+                })?;
+            } else {
+                rows.push(trimmed);
+            }
+        }
+
+        sections
+            .into_iter()
+            .map(|(name, symmetry, rows)| {
+                let template = rows.join("\n");
+                Self::new(name, &template, symmetry).map_err(|mut err| {
+                    let first_row = rows.first().map_or(name, |row| row);
+                    err.line += ParseError::at(config, first_row, "").line - 1;
+                    err
+                })
+            })
+            .collect()
+    }
+
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Moves the cells so that their bounding box starts at the origin, and sorts them.
+fn normalize(cells: &[(Point, char)]) -> Vec<(Point, char)> {
+    let top = cells.iter().map(|(point, _)| point.row).min().unwrap_or(0);
+    let left = cells.iter().map(|(point, _)| point.col).min().unwrap_or(0);
+
+    let mut normalized: Vec<(Point, char)> = cells
+        .iter()
+        .map(|&(point, c)| (point - Point::new(top, left), c))
+        .collect();
+    normalized.sort_unstable();
+    normalized
+}
+
+impl WordSearch {
+    /// Finds the placements of the stencil, shapes which look the same in several orientations
+    /// are only reported once.
+    #[must_use]
+    pub fn find_stencil(&self, stencil: &Stencil) -> Vec<StencilMatch> {
+        let mut result = vec![];
+
+        for anchor in self.grid.points() {
+            for (index, variant) in stencil.variants.iter().enumerate() {
+                let matches = variant
+                    .iter()
+                    .all(|&(offset, c)| self.grid.get(anchor + offset) == Some(&c));
+                if matches {
+                    result.push(StencilMatch {
+                        anchor,
+                        variant: index,
+                        cells: variant.iter().map(|&(offset, _)| anchor + offset).collect(),
+                    });
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+    use crate::Day4;
+
+    #[test]
+    fn symmetric_shapes_are_counted_once() {
+        let word_search = Day4::parse(".X.\nXXX\n.X.\n").unwrap();
+        let plus = Stencil::new("plus", ".X.\nXXX\n.X.", Symmetry::Reflections).unwrap();
+        let corner = Stencil::new("corner", "XX\n.X", Symmetry::Rotations).unwrap();
+
+        assert_eq!(word_search.find_stencil(&plus).len(), 1);
+        assert_eq!(word_search.find_stencil(&corner).len(), 4);
+    }
+
+    #[test]
+    fn reflections_add_mirror_images() {
+        let word_search = Day4::parse("AB.\nC..\n.BA\n..C\n").unwrap();
+        let rotations = Stencil::new("l", "AB\nC.", Symmetry::Rotations).unwrap();
+        let reflections = Stencil::new("l", "AB\nC.", Symmetry::Reflections).unwrap();
+
+        assert_eq!(
+            word_search.find_stencil(&rotations),
+            vec![StencilMatch {
+                anchor: Point::new(0, 0),
+                variant: 0,
+                cells: vec![Point::new(0, 0), Point::new(0, 1), Point::new(1, 0)]
+            }]
+        );
+        assert_eq!(word_search.find_stencil(&reflections).len(), 2);
+    }
+
+    #[test]
+    fn parses_a_config() {
+        let config = "# shapes
+[x-mas]
+symmetry = rotations
+M.S
+.A.
+M.S
+
+[pair]
+AB
+";
+        let stencils = Stencil::parse_config(config).unwrap();
+
+        assert_eq!(
+            stencils.iter().map(Stencil::name).collect::<Vec<_>>(),
+            vec!["x-mas", "pair"]
+        );
+        assert_eq!(stencils[0].variants.len(), 4);
+        assert_eq!(stencils[1].variants.len(), 1);
+    }
+
+    #[test]
+    fn locates_config_errors() {
+        assert_eq!(
+            Stencil::parse_config("[x]\nsymmetry = mirrored\n"),
+            Err(ParseError::new(
+                2,
+                12,
+                "mirrored",
+                "`none`, `rotations` or `reflections`"
+            ))
+        );
+        assert_eq!(
+            Stencil::parse_config("[x]\nAB\nABC\n"),
+            Err(ParseError::new(
+                3,
+                3,
+                "C",
+                "the end of the row after 2 cells"
+            ))
+        );
+        assert_eq!(
+            Stencil::parse_config("[x]\nsymmetry = none\n..\n..\n"),
+            Err(ParseError::new(
+                3,
+                1,
+                "..",
+                "a template cell other than the `.` wildcard"
+            ))
+        );
+        assert_eq!(
+            Stencil::parse_config("AB\n"),
+            Err(ParseError::new(1, 1, "AB", "a `[name]` header"))
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use aho_corasick::AhoCorasick;
+use grid::Point;
+
+use crate::WordSearch;
+
+/// A dictionary word found in the grid, read from `start` in `direction`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WordMatch {
+    pub start: Point,
+    pub direction: Point,
+    /// Index of the word in the searched dictionary.
+    pub word: usize,
+    /// Length of the word in chars.
+    pub len: usize,
+}
+
+impl WordMatch {
+    /// The cells covered by the word, from its first char to its last.
+    pub fn cells(&self) -> impl Iterator<Item = Point> {
+        let direction = self.direction;
+        std::iter::successors(Some(self.start), move |&point| Some(point + direction))
+            .take(self.len)
+    }
+}
+
+/// A row, column or diagonal of the grid as text, with the point of every char.
+struct Line {
+    text: String,
+    /// Byte offset of every char in `text`.
+    offsets: Vec<usize>,
+    points: Vec<Point>,
+    direction: Point,
+}
+
+impl Line {
+    fn new<'a>(cells: impl Iterator<Item = (Point, &'a char)>, direction: Point) -> Self {
+        let mut line = Self {
+            text: String::new(),
+            offsets: vec![],
+            points: vec![],
+            direction,
+        };
+        for (point, &c) in cells {
+            line.offsets.push(line.text.len());
+            line.text.push(c);
+            line.points.push(point);
+        }
+
+        line
+    }
+
+    fn reversed(&self) -> Self {
+        let points: Vec<Point> = self.points.iter().rev().copied().collect();
+        let text: String = self.text.chars().rev().collect();
+
+        Self {
+            offsets: text.char_indices().map(|(offset, _)| offset).collect(),
+            text,
+            points,
+            direction: -self.direction,
+        }
+    }
+}
+
+impl WordSearch {
+    /// Finds every occurrence of every word in all 8 directions, scanning each line of the grid
+    /// once with an Aho-Corasick automaton. Empty words never match.
+    #[must_use]
+    pub fn find_words(&self, words: &[&str]) -> Vec<WordMatch> {
+        let patterns: Vec<(usize, &str)> = words
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, word)| !word.is_empty())
+            .collect();
+        let automaton = AhoCorasick::new(patterns.iter().map(|&(_, word)| word))
+            .expect("The dictionary must fit into an automaton");
+
+        let grid = &self.grid;
+        let lines = grid
+            .rows()
+            .map(|row| Line::new(row, Point::EAST))
+            .chain(grid.columns().map(|column| Line::new(column, Point::SOUTH)))
+            .chain(
+                grid.diagonals()
+                    .map(|diagonal| Line::new(diagonal, Point::SOUTH_EAST)),
+            )
+            .chain(
+                grid.anti_diagonals()
+                    .map(|diagonal| Line::new(diagonal, Point::SOUTH_WEST)),
+            )
+            .flat_map(|line| {
+                let reversed = line.reversed();
+                [line, reversed]
+            });
+
+        let mut matches = vec![];
+        for line in lines {
+            for found in automaton.find_overlapping_iter(&line.text) {
+                let start = line
+                    .offsets
+                    .binary_search(&found.start())
+                    .expect("Matches start at a char boundary");
+                matches.push(WordMatch {
+                    start: line.points[start],
+                    direction: line.direction,
+                    word: patterns[found.pattern().as_usize()].0,
+                    len: line.offsets[start..].partition_point(|&offset| offset < found.end()),
+                });
+            }
+        }
+
+        matches.sort_unstable();
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+    use crate::Day4;
+
+    #[test]
+    fn finds_every_word_in_one_pass() {
+        let word_search = Day4::parse("XMAS\nMAS.\nAS..\nS...\n").unwrap();
+        let words = ["XMAS", "SAM", "AS", ""];
+        let matches = word_search.find_words(&words);
+
+        for (index, word) in words.iter().enumerate().take(3) {
+            let count = matches.iter().filter(|found| found.word == index).count();
+            assert_eq!(count, word_search.find_string(word).len(), "{word}");
+        }
+        assert!(matches.contains(&WordMatch {
+            start: Point::new(0, 0),
+            direction: Point::EAST,
+            word: 0,
+            len: 4
+        }));
+        assert!(matches.contains(&WordMatch {
+            start: Point::new(0, 0),
+            direction: Point::SOUTH,
+            word: 0,
+            len: 4
+        }));
+    }
+
+    #[test]
+    fn matches_single_chars_in_every_direction() {
+        let word_search = Day4::parse("AB\nBA\n").unwrap();
+
+        assert_eq!(word_search.find_words(&["A"]).len(), 16);
+    }
+}
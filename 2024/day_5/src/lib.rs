@@ -0,0 +1,293 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+    error::Error,
+    fmt,
+};
+
+use aoc_core::{ParseError, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, line_ending},
+    combinator::map_res,
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+    IResult,
+};
+
+pub type PageUpdates = Vec<Vec<i32>>;
+
+/// The rules restricted to the pages of an update require some pages to precede themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    /// The pages on or behind a cycle, in update order.
+    pub pages: Vec<i32>,
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The ordering rules for pages {:?} contain a cycle",
+            self.pages
+        )
+    }
+}
+
+impl Error for CycleError {}
+
+pub struct PageOrderingRules {
+    rules: HashSet<(i32, i32)>,
+}
+
+impl PageOrderingRules {
+    fn new(rules: Vec<(i32, i32)>) -> Self {
+        Self {
+            rules: rules.into_iter().collect(),
+        }
+    }
+
+    /// Checks every pair of pages, not only neighbours, as a rule may relate any two of them.
+    fn check_page_update_order(&self, page_update: &[i32]) -> bool {
+        page_update.iter().enumerate().all(|(i, &earlier)| {
+            page_update[i + 1..]
+                .iter()
+                .all(|&later| !self.rules.contains(&(later, earlier)))
+        })
+    }
+
+    /// Orders the pages of an update by topologically sorting the rules between them with Kahn's
+    /// algorithm. Of the pages which may come next, the one earliest in the update is taken.
+    pub fn sort_page_update(&self, page_update: &[i32]) -> Result<Vec<i32>, CycleError> {
+        let mut successors = vec![vec![]; page_update.len()];
+        let mut in_degrees = vec![0; page_update.len()];
+        for (i, &a) in page_update.iter().enumerate() {
+            for (j, &b) in page_update.iter().enumerate() {
+                if self.rules.contains(&(a, b)) {
+                    successors[i].push(j);
+                    in_degrees[j] += 1;
+                }
+            }
+        }
+
+        let mut ready: BinaryHeap<Reverse<usize>> = in_degrees
+            .iter()
+            .enumerate()
+            .filter(|&(_, &in_degree)| in_degree == 0)
+            .map(|(i, _)| Reverse(i))
+            .collect();
+
+        let mut sorted = Vec::with_capacity(page_update.len());
+        while let Some(Reverse(i)) = ready.pop() {
+            sorted.push(page_update[i]);
+            for &j in &successors[i] {
+                in_degrees[j] -= 1;
+                if in_degrees[j] == 0 {
+                    ready.push(Reverse(j));
+                }
+            }
+        }
+
+        if sorted.len() < page_update.len() {
+            return Err(CycleError {
+                pages: page_update
+                    .iter()
+                    .zip(in_degrees)
+                    .filter(|&(_, in_degree)| in_degree > 0)
+                    .map(|(&page, _)| page)
+                    .collect(),
+            });
+        }
+
+        Ok(sorted)
+    }
+}
+
+fn parse_integer(input: &str) -> IResult<&str, i32> {
+    map_res(digit1, str::parse)(input)
+}
+
+fn parse_pipe_pair(input: &str) -> IResult<&str, (i32, i32)> {
+    separated_pair(parse_integer, tag("|"), parse_integer)(input)
+}
+
+fn parse_integer_pairs(input: &str) -> IResult<&str, Vec<(i32, i32)>> {
+    separated_list1(line_ending, parse_pipe_pair)(input)
+}
+
+fn parse_comma_list(input: &str) -> IResult<&str, Vec<i32>> {
+    separated_list1(char(','), parse_integer)(input)
+}
+
+const EXPECTED: &str = "a rule like `47|53` or an update like `75,47,61`";
+
+fn parse_input(input: &str) -> IResult<&str, (PageOrderingRules, PageUpdates)> {
+    let (input, pairs) = parse_integer_pairs(input)?;
+    let (input, _) = many1(line_ending)(input)?;
+    let (input, lists) = separated_list1(line_ending, parse_comma_list)(input)?;
+
+    Ok((input, (PageOrderingRules::new(pairs), lists)))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (PageOrderingRules, PageUpdates);
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (rest, (ordering_rules, page_updates)) =
+            parse_input(input).map_err(|err| match err {
+                nom::Err::Error(err) | nom::Err::Failure(err) => {
+                    ParseError::at(input, err.input, EXPECTED)
+                }
+                nom::Err::Incomplete(_) => ParseError::end_of_input(input, EXPECTED),
+            })?;
+
+        let rest = rest.trim_start();
+        if !rest.is_empty() {
+            return Err(ParseError::at(input, rest, EXPECTED));
+        }
+
+        Ok((ordering_rules, page_updates))
+    }
+
+    fn part1((ordering_rules, page_updates): &Self::Input) -> anyhow::Result<Self::Part1> {
+        Ok(page_updates
+            .iter()
+            .filter(|page_update| {
+                page_update.len() >= 3 && ordering_rules.check_page_update_order(page_update)
+            })
+            .map(|page_update| page_update[page_update.len() / 2])
+            .sum())
+    }
+
+    fn part2((ordering_rules, page_updates): &Self::Input) -> anyhow::Result<Self::Part2> {
+        let incorrectly_sorted_pages: PageUpdates = page_updates
+            .iter()
+            .filter(|page_update| {
+                page_update.len() >= 3 && !ordering_rules.check_page_update_order(page_update)
+            })
+            .cloned()
+            .collect();
+
+        let mut middle_page_sum: i32 = 0;
+        for page_update in incorrectly_sorted_pages {
+            let page_update = ordering_rules.sort_page_update(&page_update)?;
+            middle_page_sum += page_update[page_update.len() / 2];
+        }
+
+        Ok(middle_page_sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn example_part1() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day5::part1(&input).unwrap(), 143);
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = Day5::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day5::part2(&input).unwrap(), 123);
+    }
+
+    #[test]
+    fn check_page_update_order() {
+        let (ordering_rules, page_updates) = Day5::parse(EXAMPLE).unwrap();
+
+        let correct: Vec<bool> = page_updates
+            .iter()
+            .map(|page_update| ordering_rules.check_page_update_order(page_update))
+            .collect();
+
+        assert_eq!(correct, [true, true, true, false, false, false]);
+    }
+
+    #[test]
+    fn checks_pages_which_are_not_neighbours() {
+        let ordering_rules = PageOrderingRules::new(vec![(1, 3)]);
+
+        assert!(!ordering_rules.check_page_update_order(&[3, 2, 1]));
+        assert!(ordering_rules.check_page_update_order(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn sort_page_update() {
+        let (ordering_rules, _) = Day5::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            ordering_rules.sort_page_update(&[97, 13, 75, 29, 47]),
+            Ok(vec![97, 75, 47, 29, 13])
+        );
+    }
+
+    #[test]
+    fn earliest_ready_page_goes_first() {
+        let ordering_rules = PageOrderingRules::new(vec![(3, 1)]);
+
+        assert_eq!(
+            ordering_rules.sort_page_update(&[5, 1, 4, 3, 2]),
+            Ok(vec![5, 4, 3, 1, 2])
+        );
+    }
+
+    #[test]
+    fn reports_cycles() {
+        let ordering_rules = PageOrderingRules::new(vec![(1, 2), (2, 3), (3, 1), (3, 4), (5, 1)]);
+
+        assert_eq!(
+            ordering_rules.sort_page_update(&[4, 3, 5, 2, 1]),
+            Err(CycleError {
+                pages: vec![4, 3, 2, 1]
+            })
+        );
+        assert_eq!(
+            ordering_rules.sort_page_update(&[1, 3, 4]),
+            Ok(vec![3, 1, 4])
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        aoc_core::testing::assert_real_answers::<Day5>(2024, 5);
+    }
+}
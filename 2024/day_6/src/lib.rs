@@ -0,0 +1,223 @@
+use aoc_core::{ParseError, Solution};
+use grid::{Grid, Point};
+use rayon::prelude::*;
+use rustc_hash::FxHashSet as HashSet;
+
+#[derive(Copy, Clone, Eq, Hash, PartialEq)]
+enum GuardDirection {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl GuardDirection {
+    const fn from_symbol(symbol: char) -> Option<Self> {
+        match symbol {
+            '^' => Some(Self::North),
+            '>' => Some(Self::East),
+            'v' => Some(Self::South),
+            '<' => Some(Self::West),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+struct Guard {
+    position: Point,
+    direction: GuardDirection,
+}
+
+impl Guard {
+    const fn new(position: Point, direction: GuardDirection) -> Self {
+        Self {
+            position,
+            direction,
+        }
+    }
+
+    const fn turn_right(&mut self) {
+        match self.direction {
+            GuardDirection::North => self.direction = GuardDirection::East,
+            GuardDirection::East => self.direction = GuardDirection::South,
+            GuardDirection::South => self.direction = GuardDirection::West,
+            GuardDirection::West => self.direction = GuardDirection::North,
+        }
+    }
+
+    fn move_forward(&mut self) {
+        self.position = self.get_next_forward_position();
+    }
+
+    fn get_next_forward_position(&self) -> Point {
+        match self.direction {
+            GuardDirection::North => self.position + Point::NORTH,
+            GuardDirection::East => self.position + Point::EAST,
+            GuardDirection::South => self.position + Point::SOUTH,
+            GuardDirection::West => self.position + Point::WEST,
+        }
+    }
+}
+
+struct TraverseInfo {
+    visited: HashSet<Point>,
+    is_loop: bool,
+}
+
+#[derive(Clone)]
+pub struct Lab {
+    obstacles: Grid<bool>,
+    guard: Guard,
+}
+
+impl Lab {
+    const fn new(obstacles: Grid<bool>, guard: Guard) -> Self {
+        Self { obstacles, guard }
+    }
+
+    fn guard_traverse(&self) -> TraverseInfo {
+        let mut guard = self.guard;
+
+        let mut visited = HashSet::default();
+        let mut loop_check = HashSet::default();
+
+        while self.obstacles.contains(guard.position) {
+            visited.insert(guard.position);
+            if loop_check.contains(&(guard.position, guard.direction)) {
+                return TraverseInfo {
+                    visited,
+                    is_loop: true,
+                };
+            }
+            loop_check.insert((guard.position, guard.direction));
+
+            let next_position = guard.get_next_forward_position();
+
+            if self.obstacles.get(next_position) == Some(&true) {
+                guard.turn_right();
+            } else {
+                guard.move_forward();
+            }
+        }
+
+        TraverseInfo {
+            visited,
+            is_loop: false,
+        }
+    }
+
+    fn find_obstruction_count(&self) -> i32 {
+        let traverse_info = self.guard_traverse();
+
+        if traverse_info.is_loop {
+            return 0;
+        }
+
+        let mut path = traverse_info.visited;
+        path.remove(&self.guard.position);
+
+        path.par_iter()
+            .map(|&position| {
+                let mut lab = self.clone();
+                lab.add_obstacle(position);
+                let traverse_info = lab.guard_traverse();
+
+                i32::from(traverse_info.is_loop)
+            })
+            .sum()
+    }
+
+    fn add_obstacle(&mut self, position: Point) {
+        self.obstacles[position] = true;
+    }
+}
+
+fn parse_input(input: &str) -> Result<Lab, ParseError> {
+    let grid = Grid::parse(input, |field| {
+        matches!(field, '#' | '.' | '^' | '>' | 'v' | '<').then_some(field)
+    })?;
+
+    let mut guards = grid.iter().filter_map(|(position, &field)| {
+        Some((position, field, GuardDirection::from_symbol(field)?))
+    });
+
+    let (position, _, direction) = guards
+        .next()
+        .ok_or_else(|| ParseError::end_of_input(input, "a guard (`^`, `>`, `v` or `<`)"))?;
+    if let Some((position, field, _)) = guards.next() {
+        return Err(ParseError::new(
+            position.row.unsigned_abs() as usize + 1,
+            position.col.unsigned_abs() as usize + 1,
+            field,
+            "a single guard",
+        ));
+    }
+    let guard = Guard::new(position, direction);
+
+    Ok(Lab::new(grid.map(|&field| field == '#'), guard))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Lab;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(lab: &Self::Input) -> anyhow::Result<Self::Part1> {
+        Ok(lab.guard_traverse().visited.len())
+    }
+
+    fn part2(lab: &Self::Input) -> anyhow::Result<Self::Part2> {
+        Ok(lab.find_obstruction_count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn example_part1() {
+        let lab = Day6::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day6::part1(&lab).unwrap(), 41);
+    }
+
+    #[test]
+    fn example_part2() {
+        let lab = Day6::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day6::part2(&lab).unwrap(), 6);
+    }
+
+    #[test]
+    fn rejects_a_second_guard() {
+        assert_eq!(
+            Day6::parse("^..\n.#>\n").err(),
+            Some(ParseError::new(2, 3, ">", "a single guard"))
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        aoc_core::testing::assert_real_answers::<Day6>(2024, 6);
+    }
+}
@@ -0,0 +1,201 @@
+use aoc_core::{ParseError, Solution};
+use nom::{
+    character::complete::{char, digit1, line_ending, space1},
+    combinator::{map, map_res},
+    multi::{many1, separated_list1},
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+use rayon::prelude::*;
+
+pub struct Equation {
+    result: u64,
+    operands: Vec<u64>,
+}
+
+impl Equation {
+    fn evaluate(&self, with_concatenation: bool) -> bool {
+        if self.operands.is_empty() {
+            return false;
+        }
+        if self.operands.len() == 1 {
+            return self.operands[0] == self.result;
+        }
+
+        Self::backtrack(
+            self.result,
+            self.operands[0],
+            &self.operands[1..],
+            with_concatenation,
+        )
+    }
+
+    fn backtrack(
+        result: u64,
+        acc: u64,
+        remaining_operands: &[u64],
+        with_concatenation: bool,
+    ) -> bool {
+        if remaining_operands.is_empty() {
+            return acc == result;
+        }
+
+        let mut mul_result = false;
+        let mut add_result = false;
+        let mut concat_result = false;
+        if acc * remaining_operands[0] <= result {
+            mul_result = Self::backtrack(
+                result,
+                acc * remaining_operands[0],
+                &remaining_operands[1..],
+                with_concatenation,
+            );
+        }
+        if acc + remaining_operands[0] <= result {
+            add_result = Self::backtrack(
+                result,
+                acc + remaining_operands[0],
+                &remaining_operands[1..],
+                with_concatenation,
+            );
+        }
+        if with_concatenation {
+            let concatenated_number = acc
+                * 10_u64.pow(remaining_operands[0].to_string().len().try_into().unwrap())
+                + remaining_operands[0];
+            if concatenated_number <= result {
+                concat_result = Self::backtrack(
+                    result,
+                    concatenated_number,
+                    &remaining_operands[1..],
+                    with_concatenation,
+                );
+            }
+        }
+
+        mul_result || add_result || concat_result
+    }
+}
+
+fn parse_integer(input: &str) -> IResult<&str, u64> {
+    map_res(digit1, str::parse)(input)
+}
+
+fn parse_equation(input: &str) -> IResult<&str, Equation> {
+    map(
+        separated_pair(
+            parse_integer,
+            char(':'),
+            many1(preceded(space1, parse_integer)),
+        ),
+        |(result, operands)| Equation { result, operands },
+    )(input)
+}
+
+const EXPECTED: &str = "an equation like `190: 10 19`";
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Equation>> {
+    separated_list1(line_ending, parse_equation)(input)
+}
+
+fn calibration_result(equations: &[Equation], with_concatenation: bool) -> u64 {
+    equations
+        .par_iter()
+        .filter(|eq| eq.evaluate(with_concatenation))
+        .map(|eq| eq.result)
+        .sum()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (rest, equations) = parse_input(input).map_err(|err| match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                ParseError::at(input, err.input, EXPECTED)
+            }
+            nom::Err::Incomplete(_) => ParseError::end_of_input(input, EXPECTED),
+        })?;
+
+        let rest = rest.trim_start();
+        if !rest.is_empty() {
+            return Err(ParseError::at(input, rest, EXPECTED));
+        }
+
+        Ok(equations)
+    }
+
+    fn part1(equations: &Self::Input) -> anyhow::Result<Self::Part1> {
+        Ok(calibration_result(equations, false))
+    }
+
+    fn part2(equations: &Self::Input) -> anyhow::Result<Self::Part2> {
+        Ok(calibration_result(equations, true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn example_part1() {
+        let equations = Day7::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day7::part1(&equations).unwrap(), 3749);
+    }
+
+    #[test]
+    fn example_part2() {
+        let equations = Day7::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day7::part2(&equations).unwrap(), 11387);
+    }
+
+    #[test]
+    fn evaluate() {
+        let equations = Day7::parse(EXAMPLE).unwrap();
+
+        let possible: Vec<u64> = equations
+            .iter()
+            .filter(|eq| eq.evaluate(false))
+            .map(|eq| eq.result)
+            .collect();
+        assert_eq!(possible, [190, 3267, 292]);
+
+        let possible: Vec<u64> = equations
+            .iter()
+            .filter(|eq| eq.evaluate(true))
+            .map(|eq| eq.result)
+            .collect();
+        assert_eq!(possible, [190, 3267, 156, 7290, 192, 292]);
+    }
+
+    #[test]
+    fn rejects_a_malformed_equation() {
+        assert_eq!(
+            Day7::parse("190: 10 19\n3267 81 40 27\n").err(),
+            Some(ParseError::new(2, 1, "3267", EXPECTED))
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        aoc_core::testing::assert_real_answers::<Day7>(2024, 7);
+    }
+}
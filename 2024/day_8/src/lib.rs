@@ -0,0 +1,152 @@
+use aoc_core::{ParseError, Solution};
+use grid::{Grid, Point};
+use itertools::Itertools;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+pub struct AntennaMap {
+    grid: Grid<char>,
+    antennas: HashMap<char, Vec<Point>>,
+}
+
+impl AntennaMap {
+    fn new(grid: Grid<char>) -> Self {
+        let mut antennas = HashMap::default();
+
+        grid.iter()
+            .filter(|(_, &c)| c != '.')
+            .for_each(|(pos, &c)| antennas.entry(c).or_insert_with(Vec::new).push(pos));
+
+        Self { grid, antennas }
+    }
+
+    fn find_antinodes_within_distance(&self) -> u32 {
+        let mut antinodes = HashSet::default();
+
+        for positions in self.antennas.values() {
+            let antenna_pairs = positions.iter().combinations(2);
+            for pair in antenna_pairs {
+                let pos1 = *pair[0];
+                let pos2 = *pair[1];
+
+                let distance = pos2 - pos1;
+                let antinode_1 = pos1 - distance;
+                let antinode_2 = pos2 + distance;
+
+                if self.validate_antinode_position(antinode_1) {
+                    antinodes.insert(antinode_1);
+                }
+
+                if self.validate_antinode_position(antinode_2) {
+                    antinodes.insert(antinode_2);
+                }
+            }
+        }
+
+        antinodes.len().try_into().unwrap()
+    }
+
+    fn find_antinodes_any_distance(&self) -> u32 {
+        let mut antinodes = HashSet::default();
+
+        for positions in self.antennas.values() {
+            let antenna_pairs = positions.iter().combinations(2);
+            for pair in antenna_pairs {
+                let pos1 = *pair[0];
+                let pos2 = *pair[1];
+
+                let distance = pos2 - pos1;
+
+                let divisor = Self::gcd(distance.row, distance.col);
+                let step = Point::new(distance.row / divisor, distance.col / divisor);
+
+                for (start_n, increment) in [(0, 1), (-1, -1)] {
+                    let mut n = start_n;
+                    while self.validate_antinode_position(pos1 + step * n) {
+                        antinodes.insert(pos1 + step * n);
+                        n += increment;
+                    }
+                }
+            }
+        }
+
+        antinodes.len().try_into().unwrap()
+    }
+
+    const fn validate_antinode_position(&self, position: Point) -> bool {
+        self.grid.contains(position)
+    }
+
+    const fn gcd(a: i32, b: i32) -> i32 {
+        if b == 0 {
+            return a;
+        }
+
+        Self::gcd(b, a % b)
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = AntennaMap;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(AntennaMap::new(Grid::parse(input, Some)?))
+    }
+
+    fn part1(map: &Self::Input) -> anyhow::Result<Self::Part1> {
+        Ok(map.find_antinodes_within_distance())
+    }
+
+    fn part2(map: &Self::Input) -> anyhow::Result<Self::Part2> {
+        Ok(map.find_antinodes_any_distance())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn example_part1() {
+        let map = Day8::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day8::part1(&map).unwrap(), 14);
+    }
+
+    #[test]
+    fn example_part2() {
+        let map = Day8::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day8::part2(&map).unwrap(), 34);
+    }
+
+    #[test]
+    fn non_square_grid() {
+        let map = Day8::parse("..........\n...a.a....\n..........\n").unwrap();
+
+        assert_eq!(map.find_antinodes_within_distance(), 2);
+        assert_eq!(map.find_antinodes_any_distance(), 10);
+    }
+
+    #[test]
+    fn real_input() {
+        aoc_core::testing::assert_real_answers::<Day8>(2024, 8);
+    }
+}
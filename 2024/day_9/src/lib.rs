@@ -1,25 +1,42 @@
 use std::cmp::Ordering;
 
+use aoc_core::{ParseError, Solution};
+
 #[derive(Clone, Copy, Debug)]
 enum FileBlock {
     File(u32, u32),
     Empty(u32),
 }
 
-struct FileSystem {
+#[derive(Clone)]
+pub struct FileSystem {
     blocks: Vec<FileBlock>,
 }
 
 impl FileSystem {
-    fn new(disk_map: &str) -> Self {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let disk_map = input.trim();
+        if disk_map.is_empty() {
+            return Err(ParseError::end_of_input(input, "a digit"));
+        }
+
         let mut blocks = vec![];
 
         let mut is_file = true;
         let mut file_id = 0;
-        for c in disk_map.chars() {
-            let block_count = c.to_digit(10).unwrap();
+        for (index, c) in disk_map.char_indices() {
+            let symbol = &disk_map[index..index + c.len_utf8()];
+            let block_count = c
+                .to_digit(10)
+                .ok_or_else(|| ParseError::at(input, symbol, "a digit"))?;
             if is_file {
-                assert!(block_count > 0, "File must have at least one block");
+                if block_count == 0 {
+                    return Err(ParseError::at(
+                        input,
+                        symbol,
+                        "a file of at least one block",
+                    ));
+                }
                 blocks.push(FileBlock::File(file_id, block_count));
                 file_id += 1;
             } else {
@@ -29,7 +46,7 @@ impl FileSystem {
             is_file = !is_file;
         }
 
-        Self { blocks }
+        Ok(Self { blocks })
     }
 
     fn compress_fragmented(&mut self) -> u64 {
@@ -163,14 +180,76 @@ impl FileSystem {
     }
 }
 
-fn main() {
-    let input = include_str!("input.txt").trim();
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = FileSystem;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        FileSystem::parse(input)
+    }
+
+    fn part1(fs: &Self::Input) -> anyhow::Result<Self::Part1> {
+        Ok(fs.clone().compress_fragmented())
+    }
+
+    fn part2(fs: &Self::Input) -> anyhow::Result<Self::Part2> {
+        Ok(fs.clone().compress_non_fragmented())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2333133121414131402\n";
 
-    let mut fs = FileSystem::new(input);
-    let checksum = fs.compress_fragmented();
-    println!("Checksum after fragmented compression: {checksum}");
+    #[test]
+    fn example_part1() {
+        let fs = Day9::parse(EXAMPLE).unwrap();
 
-    let mut fs = FileSystem::new(input);
-    let checksum = fs.compress_non_fragmented();
-    println!("Checksum after non-fragmented compression: {checksum}");
+        assert_eq!(Day9::part1(&fs).unwrap(), 1928);
+    }
+
+    #[test]
+    fn example_part2() {
+        let fs = Day9::parse(EXAMPLE).unwrap();
+
+        assert_eq!(Day9::part2(&fs).unwrap(), 2858);
+    }
+
+    #[test]
+    fn checksum() {
+        // 0..111....22222
+        let fs = FileSystem::parse("12345").unwrap();
+
+        assert_eq!(fs.checksum(), 3 + 4 + 5 + 2 * (10 + 11 + 12 + 13 + 14));
+    }
+
+    #[test]
+    fn rejects_non_digits() {
+        assert_eq!(
+            Day9::parse("1234a\n").err(),
+            Some(ParseError::new(1, 5, "a", "a digit"))
+        );
+    }
+
+    #[test]
+    fn rejects_an_empty_disk_map() {
+        assert_eq!(
+            Day9::parse("").err(),
+            Some(ParseError::new(1, 1, "", "a digit"))
+        );
+        assert_eq!(
+            Day9::parse("\n").err(),
+            Some(ParseError::new(2, 1, "", "a digit"))
+        );
+    }
+
+    #[test]
+    fn real_input() {
+        aoc_core::testing::assert_real_answers::<Day9>(2024, 9);
+    }
 }
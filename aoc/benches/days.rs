@@ -0,0 +1,118 @@
+use std::{fs, hint::black_box, path::Path};
+
+use aoc_2020_day_1::{KSum, Strategy};
+use aoc_core::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn read_input(year: u16, day: u8) -> Option<String> {
+    let inputs_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs");
+    let path = aoc_core::input_path(&inputs_dir, year, day);
+
+    let input = fs::read_to_string(&path).ok();
+    if input.is_none() {
+        eprintln!(
+            "Skipping {year} day {day}, {} does not exist",
+            path.display()
+        );
+    }
+    input
+}
+
+/// Times parsing and both parts separately on the locally stored puzzle input.
+fn bench_phases<S: Solution>(c: &mut Criterion, year: u16, day: u8) {
+    let Some(raw_input) = read_input(year, day) else {
+        return;
+    };
+    let input = S::parse(&raw_input).expect("Failed to parse the input");
+
+    let mut group = c.benchmark_group(format!("{year}/day_{day}"));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&raw_input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input))));
+    group.finish();
+}
+
+type Variant<'a, I, R> = (&'a str, &'a dyn Fn(&I) -> R);
+
+/// Times alternative implementations of the same part side by side in one group.
+fn bench_variants<I, R>(c: &mut Criterion, name: &str, input: &I, variants: &[Variant<I, R>]) {
+    let mut group = c.benchmark_group(name);
+    for (variant, run) in variants {
+        group.bench_function(*variant, |b| b.iter(|| run(black_box(input))));
+    }
+    group.finish();
+}
+
+/// A pool with a single rayon thread to see what the parallel version gains. Built once per
+/// benchmark, so that spawning the thread is not part of the timed iterations.
+fn single_thread_pool() -> rayon::ThreadPool {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .expect("Failed to build a single threaded pool")
+}
+
+fn bench_parallel_part2<S>(c: &mut Criterion, year: u16, day: u8)
+where
+    S: Solution,
+    S::Input: Sync,
+    S::Part2: Send,
+{
+    let Some(raw_input) = read_input(year, day) else {
+        return;
+    };
+    let input = S::parse(&raw_input).expect("Failed to parse the input");
+    let pool = single_thread_pool();
+
+    bench_variants(
+        c,
+        &format!("{year}/day_{day}/part2"),
+        &input,
+        &[
+            ("parallel", &|input| S::part2(input)),
+            ("single_thread", &|input| pool.install(|| S::part2(input))),
+        ],
+    );
+}
+
+fn phases(c: &mut Criterion) {
+    bench_phases::<aoc_2020_day_1::Day1>(c, 2020, 1);
+    bench_phases::<aoc_2024_day_1::Day1>(c, 2024, 1);
+    bench_phases::<aoc_2024_day_2::Day2>(c, 2024, 2);
+    bench_phases::<aoc_2024_day_3::Day3>(c, 2024, 3);
+    bench_phases::<aoc_2024_day_4::Day4>(c, 2024, 4);
+    bench_phases::<aoc_2024_day_5::Day5>(c, 2024, 5);
+    bench_phases::<aoc_2024_day_6::Day6>(c, 2024, 6);
+    bench_phases::<aoc_2024_day_7::Day7>(c, 2024, 7);
+    bench_phases::<aoc_2024_day_8::Day8>(c, 2024, 8);
+    bench_phases::<aoc_2024_day_9::Day9>(c, 2024, 9);
+    bench_phases::<aoc_2024_day_10::Day10>(c, 2024, 10);
+}
+
+fn variants(c: &mut Criterion) {
+    if let Some(raw_input) = read_input(2020, 1) {
+        let nums = aoc_2020_day_1::Day1::parse(&raw_input).expect("Failed to parse the input");
+
+        let k_sum = |strategy| {
+            move |nums: &Vec<i64>| KSum::new(2020, 3).with_strategy(strategy).first(nums)
+        };
+
+        bench_variants(
+            c,
+            "2020/day_1/part2",
+            &nums,
+            &[
+                ("hash_set", &k_sum(Strategy::HashSet)),
+                ("two_pointers", &k_sum(Strategy::TwoPointers)),
+                ("meet_in_the_middle", &k_sum(Strategy::MeetInTheMiddle)),
+            ],
+        );
+    }
+
+    bench_parallel_part2::<aoc_2024_day_6::Day6>(c, 2024, 6);
+    bench_parallel_part2::<aoc_2024_day_7::Day7>(c, 2024, 7);
+    bench_parallel_part2::<aoc_2024_day_10::Day10>(c, 2024, 10);
+}
+
+criterion_group!(benches, phases, variants);
+criterion_main!(benches);
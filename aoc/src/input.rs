@@ -0,0 +1,52 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl InputSource {
+    pub fn resolve(input: Option<PathBuf>, inputs_dir: &Path, year: u16, day: u8) -> Self {
+        match input {
+            Some(path) if path.as_os_str() == "-" => Self::Stdin,
+            Some(path) => Self::File(path),
+            None => Self::File(aoc_core::input_path(inputs_dir, year, day)),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Self::Stdin => "<stdin>".to_string(),
+            Self::File(path) => path.display().to_string(),
+        }
+    }
+
+    pub fn read(&self) -> anyhow::Result<String> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Failed to read the input from stdin")?;
+                Ok(input)
+            }
+            Self::File(path) => fs::read_to_string(path).map_err(|err| {
+                if err.kind() == io::ErrorKind::NotFound {
+                    anyhow::anyhow!(
+                        "Input file {} does not exist, save the puzzle input there or pass --input <PATH>",
+                        path.display()
+                    )
+                } else {
+                    anyhow::Error::new(err)
+                        .context(format!("Failed to read the input file {}", path.display()))
+                }
+            }),
+        }
+    }
+}
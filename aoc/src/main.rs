@@ -0,0 +1,81 @@
+mod input;
+mod registry;
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use aoc_core::{ParseError, Part};
+use clap::{Parser, Subcommand};
+use input::InputSource;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a solver for the given year and day
+    Run {
+        year: u16,
+        day: u8,
+        /// Run only the given part, both parts are run by default
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the puzzle input from the given file, use `-` to read it from stdin
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Directory with puzzle inputs stored as `<year>/day_<day>.txt`
+        #[arg(long, default_value = "inputs")]
+        inputs_dir: PathBuf,
+    },
+    /// List all registered solvers
+    List,
+}
+
+fn run(year: u16, day: u8, part: Option<u8>, source: &InputSource) -> anyhow::Result<()> {
+    let solver = registry::find(year, day)
+        .with_context(|| format!("No solver registered for {year} day {day}"))?;
+    let input = source.read()?;
+
+    let part = part.map(|part| if part == 1 { Part::One } else { Part::Two });
+    let answers =
+        (solver.solve)(&input, part).map_err(|err| match err.downcast::<ParseError>() {
+            Ok(err) => anyhow::anyhow!(err.render(&source.name(), &input)),
+            Err(err) => err,
+        })?;
+
+    if let Some(answer) = answers.part1 {
+        println!("Part 1: {answer}");
+    }
+    if let Some(answer) = answers.part2 {
+        println!("Part 2: {answer}");
+    }
+
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    match Cli::parse().command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            inputs_dir,
+        } => run(
+            year,
+            day,
+            part,
+            &InputSource::resolve(input, &inputs_dir, year, day),
+        ),
+        Command::List => {
+            for solver in registry::SOLVERS {
+                println!("{} day {}", solver.year, solver.day);
+            }
+            Ok(())
+        }
+    }
+}
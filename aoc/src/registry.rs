@@ -0,0 +1,37 @@
+use aoc_core::{Answers, Part};
+
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub solve: fn(&str, Option<Part>) -> anyhow::Result<Answers>,
+}
+
+macro_rules! solver {
+    ($year:literal, $day:literal, $solution:path) => {
+        Solver {
+            year: $year,
+            day: $day,
+            solve: aoc_core::solve::<$solution>,
+        }
+    };
+}
+
+pub const SOLVERS: &[Solver] = &[
+    solver!(2020, 1, aoc_2020_day_1::Day1),
+    solver!(2024, 1, aoc_2024_day_1::Day1),
+    solver!(2024, 2, aoc_2024_day_2::Day2),
+    solver!(2024, 3, aoc_2024_day_3::Day3),
+    solver!(2024, 4, aoc_2024_day_4::Day4),
+    solver!(2024, 5, aoc_2024_day_5::Day5),
+    solver!(2024, 6, aoc_2024_day_6::Day6),
+    solver!(2024, 7, aoc_2024_day_7::Day7),
+    solver!(2024, 8, aoc_2024_day_8::Day8),
+    solver!(2024, 9, aoc_2024_day_9::Day9),
+    solver!(2024, 10, aoc_2024_day_10::Day10),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Solver> {
+    SOLVERS
+        .iter()
+        .find(|solver| solver.year == year && solver.day == day)
+}
@@ -0,0 +1,65 @@
+pub mod parse;
+pub mod testing;
+
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+pub use parse::ParseError;
+
+/// A puzzle solution which parses the input into a typed model once and then answers both parts
+/// from it.
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::Part1>;
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Part2>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+#[derive(Debug, Default)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Parses the input and renders the answer of the requested part, or of both parts when no part
+/// is given.
+pub fn solve<S: Solution>(input: &str, part: Option<Part>) -> anyhow::Result<Answers> {
+    let input = S::parse(input)?;
+    let mut answers = Answers::default();
+
+    if part.is_none_or(|part| part == Part::One) {
+        answers.part1 = Some(S::part1(&input)?.to_string());
+    }
+    if part.is_none_or(|part| part == Part::Two) {
+        answers.part2 = Some(S::part2(&input)?.to_string());
+    }
+
+    Ok(answers)
+}
+
+/// Conventional location of a puzzle input, `<inputs_dir>/<year>/day_<day>.txt`.
+#[must_use]
+pub fn input_path(inputs_dir: &Path, year: u16, day: u8) -> PathBuf {
+    inputs_dir
+        .join(year.to_string())
+        .join(format!("day_{day}.txt"))
+}
+
+/// Conventional location of the known answers for a puzzle input, stored next to it.
+#[must_use]
+pub fn answers_path(inputs_dir: &Path, year: u16, day: u8) -> PathBuf {
+    input_path(inputs_dir, year, day).with_extension("answers")
+}
@@ -0,0 +1,136 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// An input which does not match the puzzle format, located at a 1-based line and column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending text, empty when the input ended too early.
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            text: text.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Locates `found`, which must be a slice of `input`, and reports the token it starts with.
+    pub fn at(input: &str, found: &str, expected: impl Into<String>) -> Self {
+        let offset = (found.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .expect("The offending text must be a slice of the input");
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let token = found.split(char::is_whitespace).next().unwrap_or_default();
+
+        Self::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            token,
+            expected,
+        )
+    }
+
+    /// Reports that the input ended before the expected text.
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], expected)
+    }
+
+    /// Renders the error like a compiler diagnostic, quoting the offending line of the input.
+    #[must_use]
+    pub fn render(&self, source_name: &str, input: &str) -> String {
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let source_line = input.lines().nth(self.line - 1).unwrap_or_default();
+        let marker = "^".repeat(self.text.chars().count().max(1));
+
+        format!(
+            "{message}\n{gutter}--> {source_name}:{line}:{column}\n{gutter} |\n{line_number} | {source_line}\n{gutter} | {padding}{marker}",
+            message = self.message(),
+            line = self.line,
+            column = self.column,
+            padding = " ".repeat(self.column - 1),
+        )
+    }
+
+    fn message(&self) -> String {
+        if self.text.is_empty() {
+            format!("expected {}, found nothing", self.expected)
+        } else {
+            format!("expected {}, found `{}`", self.expected, self.text)
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, column {}",
+            self.message(),
+            self.line,
+            self.column
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a number from `token`, a slice of `input`.
+pub fn number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, "a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_a_slice_of_the_input() {
+        let input = "1 2\n3 x4 5\n";
+        let found = &input[6..];
+
+        assert_eq!(
+            ParseError::at(input, found, "a number"),
+            ParseError::new(2, 3, "x4", "a number")
+        );
+    }
+
+    #[test]
+    fn locates_the_end_of_the_input() {
+        assert_eq!(
+            ParseError::end_of_input("1 2\n", "a number"),
+            ParseError::new(2, 1, "", "a number")
+        );
+    }
+
+    #[test]
+    fn renders_a_diagnostic() {
+        let input = "1 2\n3 x4 5\n";
+        let error = number::<i32>(input, &input[6..8]).unwrap_err();
+
+        assert_eq!(
+            error.render("day_1.txt", input),
+            "expected a number, found `x4`
+ --> day_1.txt:2:3
+  |
+2 | 3 x4 5
+  |   ^^"
+        );
+    }
+}
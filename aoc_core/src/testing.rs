@@ -0,0 +1,23 @@
+use std::{fs, path::Path};
+
+use crate::{answers_path, input_path, solve, Solution};
+
+/// Checks the answers of both parts against the locally stored puzzle input.
+///
+/// The check is skipped when the input or its answers file is missing. The answers file holds the
+/// part 1 answer on the first line and the part 2 answer on the second one.
+pub fn assert_real_answers<S: Solution>(year: u16, day: u8) {
+    let inputs_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs");
+    let (Ok(input), Ok(expected)) = (
+        fs::read_to_string(input_path(&inputs_dir, year, day)),
+        fs::read_to_string(answers_path(&inputs_dir, year, day)),
+    ) else {
+        return;
+    };
+
+    let answers = solve::<S>(&input, None).expect("Solution failed on the real input");
+    let mut expected = expected.lines().map(str::trim);
+
+    assert_eq!(answers.part1.as_deref(), expected.next(), "Part 1 answer");
+    assert_eq!(answers.part2.as_deref(), expected.next(), "Part 2 answer");
+}
@@ -0,0 +1,74 @@
+use std::{error::Error, fmt};
+
+use aoc_core::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridParseError {
+    Empty,
+    UnevenRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+        line: String,
+    },
+    InvalidCell {
+        row: usize,
+        col: usize,
+        cell: char,
+    },
+}
+
+impl fmt::Display for GridParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Grid input is empty"),
+            Self::UnevenRow {
+                row,
+                expected,
+                found,
+                ..
+            } => write!(
+                f,
+                "Row {row} has width {found}, each row must have the same width {expected}"
+            ),
+            Self::InvalidCell { row, col, cell } => {
+                write!(f, "Unexpected symbol {cell:?} at row {row}, column {col}")
+            }
+        }
+    }
+}
+
+impl Error for GridParseError {}
+
+impl From<GridParseError> for ParseError {
+    fn from(err: GridParseError) -> Self {
+        match err {
+            GridParseError::Empty => Self::new(1, 1, "", "at least one row"),
+            GridParseError::UnevenRow {
+                row,
+                expected,
+                found,
+                ..
+            } if found < expected => Self::new(
+                row + 1,
+                found + 1,
+                "",
+                format!("{} more cells", expected - found),
+            ),
+            GridParseError::UnevenRow {
+                row,
+                expected,
+                line,
+                ..
+            } => Self::new(
+                row + 1,
+                expected + 1,
+                line.chars().skip(expected).collect::<String>(),
+                format!("the end of the row after {expected} cells"),
+            ),
+            GridParseError::InvalidCell { row, col, cell } => {
+                Self::new(row + 1, col + 1, cell, "a grid cell")
+            }
+        }
+    }
+}
@@ -0,0 +1,294 @@
+mod error;
+mod point;
+mod shape;
+
+use std::ops::{Index, IndexMut};
+
+pub use error::GridParseError;
+pub use point::Point;
+pub use shape::Shape;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    shape: Shape,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from row-major cells.
+    #[must_use]
+    pub fn new(cells: Vec<T>, width: usize, height: usize) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "Cell count must match the grid dimensions"
+        );
+
+        Self {
+            cells,
+            shape: Shape::new(width, height),
+        }
+    }
+
+    /// Parses a grid of text lines, mapping every char to a cell. Returns an error when the input
+    /// is empty, rows have different widths or the mapping rejects a char.
+    pub fn parse(
+        input: &str,
+        mut parse_cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, GridParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let row_start = cells.len();
+            for (col, c) in line.chars().enumerate() {
+                let cell =
+                    parse_cell(c).ok_or(GridParseError::InvalidCell { row, col, cell: c })?;
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - row_start;
+            let expected = *width.get_or_insert(row_width);
+            if row_width != expected {
+                return Err(GridParseError::UnevenRow {
+                    row,
+                    expected,
+                    found: row_width,
+                    line: line.to_string(),
+                });
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Self::new(cells, width, height)),
+            _ => Err(GridParseError::Empty),
+        }
+    }
+
+    #[must_use]
+    pub const fn shape(&self) -> Shape {
+        self.shape
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.shape.width()
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.shape.height()
+    }
+
+    /// Creates a grid of the same dimensions with every cell mapped.
+    #[must_use]
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            shape: self.shape,
+        }
+    }
+
+    #[must_use]
+    pub const fn contains(&self, point: Point) -> bool {
+        self.shape.contains(point)
+    }
+
+    #[must_use]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.shape
+            .row_major_index(point)
+            .map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.shape
+            .row_major_index(point)
+            .map(|index| &mut self.cells[index])
+    }
+
+    /// All points of the grid in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        self.shape.points()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// In-bounds 4-connected neighbours of the point.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Point::ORTHOGONAL
+            .into_iter()
+            .map(move |direction| point + direction)
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// In-bounds 8-connected neighbours of the point.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Point::ALL_DIRECTIONS
+            .into_iter()
+            .map(move |direction| point + direction)
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// Walks from the start point in the given direction until the walk leaves the grid.
+    pub fn ray(&self, start: Point, direction: Point) -> impl Iterator<Item = (Point, &T)> {
+        std::iter::successors(Some(start), move |&point| Some(point + direction))
+            .map_while(|point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    /// Rows from top to bottom, each read from left to right.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        self.shape
+            .rows()
+            .map(|row| self.ray(Point::new(row, 0), Point::EAST))
+    }
+
+    /// Columns from left to right, each read from top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        self.shape
+            .cols()
+            .map(|col| self.ray(Point::new(0, col), Point::SOUTH))
+    }
+
+    /// Diagonals running from the top-left to the bottom-right.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        let left_edge = self
+            .shape
+            .rows()
+            .skip(1)
+            .rev()
+            .map(|row| Point::new(row, 0));
+        let top_edge = self.shape.cols().map(|col| Point::new(0, col));
+
+        left_edge
+            .chain(top_edge)
+            .map(|start| self.ray(start, Point::SOUTH_EAST))
+    }
+
+    /// Diagonals running from the top-right to the bottom-left.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Point, &T)>> {
+        let last_col = self.shape.cols().end - 1;
+        let top_edge = self.shape.cols().map(|col| Point::new(0, col));
+        let right_edge = self
+            .shape
+            .rows()
+            .skip(1)
+            .map(move |row| Point::new(row, last_col));
+
+        top_edge
+            .chain(right_edge)
+            .map(|start| self.ray(start, Point::SOUTH_WEST))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).expect("Point is out of the grid bounds")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .expect("Point is out of the grid bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::ParseError;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn text_grid() -> impl Strategy<Value = Vec<String>> {
+        (1..30_usize, 1..30_usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(
+                prop::collection::vec(prop::char::range('a', 'z'), width)
+                    .prop_map(|chars| chars.into_iter().collect::<String>()),
+                height,
+            )
+        })
+    }
+
+    fn covers_every_point_once<'a>(
+        grid: &Grid<char>,
+        lines: impl Iterator<Item = impl Iterator<Item = (Point, &'a char)>>,
+    ) -> bool {
+        let mut points: Vec<Point> = lines.flatten().map(|(point, _)| point).collect();
+        points.sort_unstable();
+
+        points == grid.points().collect::<Vec<_>>()
+    }
+
+    proptest! {
+        #[test]
+        fn parsed_cells_match_the_text(lines in text_grid()) {
+            let grid = Grid::parse(&lines.join("\n"), Some).unwrap();
+
+            prop_assert_eq!(grid.height(), lines.len());
+            prop_assert_eq!(grid.width(), lines[0].len());
+            for (row, line) in lines.iter().enumerate() {
+                for (col, c) in line.chars().enumerate() {
+                    let point = Point::new(i32::try_from(row).unwrap(), i32::try_from(col).unwrap());
+                    prop_assert_eq!(grid.get(point), Some(&c));
+                }
+            }
+        }
+
+        #[test]
+        fn views_cover_every_cell_once(lines in text_grid()) {
+            let grid = Grid::parse(&lines.join("\n"), Some).unwrap();
+
+            prop_assert!(covers_every_point_once(&grid, grid.rows()));
+            prop_assert!(covers_every_point_once(&grid, grid.columns()));
+            prop_assert!(covers_every_point_once(&grid, grid.diagonals()));
+            prop_assert!(covers_every_point_once(&grid, grid.anti_diagonals()));
+        }
+    }
+
+    #[test]
+    fn rejects_uneven_rows() {
+        assert_eq!(
+            Grid::parse("abc\nab", Some),
+            Err(GridParseError::UnevenRow {
+                row: 1,
+                expected: 3,
+                found: 2,
+                line: "ab".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn locates_parse_errors() {
+        let err = ParseError::from(
+            Grid::parse(
+                "abc
+abcde",
+                Some,
+            )
+            .unwrap_err(),
+        );
+        assert_eq!(
+            err,
+            ParseError::new(2, 4, "de", "the end of the row after 3 cells")
+        );
+
+        let err = ParseError::from(
+            Grid::parse(
+                "abc
+a?c",
+                |c| c.is_alphabetic().then_some(c),
+            )
+            .unwrap_err(),
+        );
+        assert_eq!(err, ParseError::new(2, 2, "?", "a grid cell"));
+    }
+}
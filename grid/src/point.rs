@@ -0,0 +1,82 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Point {
+    pub const NORTH: Self = Self::new(-1, 0);
+    pub const EAST: Self = Self::new(0, 1);
+    pub const SOUTH: Self = Self::new(1, 0);
+    pub const WEST: Self = Self::new(0, -1);
+    pub const NORTH_EAST: Self = Self::new(-1, 1);
+    pub const SOUTH_EAST: Self = Self::new(1, 1);
+    pub const SOUTH_WEST: Self = Self::new(1, -1);
+    pub const NORTH_WEST: Self = Self::new(-1, -1);
+
+    /// Unit steps to the 4-connected neighbours, clockwise from north.
+    pub const ORTHOGONAL: [Self; 4] = [Self::NORTH, Self::EAST, Self::SOUTH, Self::WEST];
+
+    /// Unit steps to the 8-connected neighbours, clockwise from north.
+    pub const ALL_DIRECTIONS: [Self; 8] = [
+        Self::NORTH,
+        Self::NORTH_EAST,
+        Self::EAST,
+        Self::SOUTH_EAST,
+        Self::SOUTH,
+        Self::SOUTH_WEST,
+        Self::WEST,
+        Self::NORTH_WEST,
+    ];
+
+    #[must_use]
+    pub const fn new(row: i32, col: i32) -> Self {
+        Self { row, col }
+    }
+
+    /// Rotates a direction by 90 degrees clockwise.
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        Self::new(self.col, -self.row)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: i32) -> Self {
+        Self::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.row, -self.col)
+    }
+}
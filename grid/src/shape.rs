@@ -0,0 +1,169 @@
+use std::ops::Range;
+
+use crate::Point;
+
+/// Dimensions of a rectangular grid and the conversions between points and flat indices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Shape {
+    width: i32,
+    height: i32,
+}
+
+impl Shape {
+    #[must_use]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width: i32::try_from(width).expect("Grid width must fit into point coordinates"),
+            height: i32::try_from(height).expect("Grid height must fit into point coordinates"),
+        }
+    }
+
+    #[must_use]
+    pub const fn width(self) -> usize {
+        self.width.unsigned_abs() as usize
+    }
+
+    #[must_use]
+    pub const fn height(self) -> usize {
+        self.height.unsigned_abs() as usize
+    }
+
+    #[must_use]
+    pub const fn len(self) -> usize {
+        self.width() * self.height()
+    }
+
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.len() == 0
+    }
+
+    /// The same dimensions with rows and columns swapped.
+    #[must_use]
+    pub const fn transposed(self) -> Self {
+        Self {
+            width: self.height,
+            height: self.width,
+        }
+    }
+
+    #[must_use]
+    pub const fn contains(self, point: Point) -> bool {
+        point.row >= 0 && point.col >= 0 && point.row < self.height && point.col < self.width
+    }
+
+    /// Index of the point when rows are stored one after another.
+    #[must_use]
+    pub fn row_major_index(self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| usize::try_from(point.row * self.width + point.col).ok())
+            .flatten()
+    }
+
+    /// Index of the point when columns are stored one after another.
+    #[must_use]
+    pub fn column_major_index(self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| usize::try_from(point.col * self.height + point.row).ok())
+            .flatten()
+    }
+
+    #[must_use]
+    pub fn point_from_row_major(self, index: usize) -> Option<Point> {
+        let index = i32::try_from(index).ok()?;
+        (index < self.width * self.height)
+            .then(|| Point::new(index / self.width, index % self.width))
+    }
+
+    #[must_use]
+    pub fn point_from_column_major(self, index: usize) -> Option<Point> {
+        let index = i32::try_from(index).ok()?;
+        (index < self.width * self.height)
+            .then(|| Point::new(index % self.height, index / self.height))
+    }
+
+    /// All points of the shape in row-major order.
+    pub fn points(self) -> impl Iterator<Item = Point> {
+        self.rows()
+            .flat_map(move |row| self.cols().map(move |col| Point::new(row, col)))
+    }
+
+    pub(crate) const fn rows(self) -> Range<i32> {
+        0..self.height
+    }
+
+    pub(crate) const fn cols(self) -> Range<i32> {
+        0..self.width
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn shape_and_point() -> impl Strategy<Value = (Shape, Point)> {
+        (1..40_usize, 1..40_usize).prop_flat_map(|(width, height)| {
+            let row = 0..i32::try_from(height).unwrap();
+            let col = 0..i32::try_from(width).unwrap();
+            (Just(Shape::new(width, height)), row, col)
+                .prop_map(|(shape, row, col)| (shape, Point::new(row, col)))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn row_major_index_round_trips((shape, point) in shape_and_point()) {
+            let index = shape.row_major_index(point).unwrap();
+
+            prop_assert!(index < shape.len());
+            prop_assert_eq!(shape.point_from_row_major(index), Some(point));
+        }
+
+        #[test]
+        fn column_major_index_round_trips((shape, point) in shape_and_point()) {
+            let index = shape.column_major_index(point).unwrap();
+
+            prop_assert!(index < shape.len());
+            prop_assert_eq!(shape.point_from_column_major(index), Some(point));
+        }
+
+        #[test]
+        fn column_major_is_row_major_of_transposed((shape, point) in shape_and_point()) {
+            let transposed_point = Point::new(point.col, point.row);
+
+            prop_assert_eq!(
+                shape.column_major_index(point),
+                shape.transposed().row_major_index(transposed_point)
+            );
+        }
+
+        #[test]
+        fn row_major_indices_are_a_bijection(width in 1..40_usize, height in 1..40_usize) {
+            let shape = Shape::new(width, height);
+            let indices: Vec<usize> = shape
+                .points()
+                .map(|point| shape.row_major_index(point).unwrap())
+                .collect();
+
+            prop_assert_eq!(indices, (0..shape.len()).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn points_outside_have_no_index(
+            (shape, point) in shape_and_point(),
+            offset in prop::sample::select(Point::ALL_DIRECTIONS.to_vec()),
+        ) {
+            let outside = Point::new(
+                if offset.row < 0 { -1 - point.row } else { point.row + offset.row * 40 },
+                if offset.col < 0 { -1 - point.col } else { point.col + offset.col * 40 },
+            );
+
+            prop_assert!(!shape.contains(outside));
+            prop_assert_eq!(shape.row_major_index(outside), None);
+            prop_assert_eq!(shape.column_major_index(outside), None);
+            prop_assert_eq!(shape.point_from_row_major(shape.len()), None);
+        }
+    }
+}